@@ -0,0 +1,45 @@
+//! Generates the registry of the solutions in `src/bin`, so that the main binary can run any of
+//! them in process.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let mut solutions: Vec<(u16, u8)> = fs::read_dir(Path::new(&manifest_dir).join("src/bin"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().join("main.rs").is_file())
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    let (year, day) = name.split_once('-')?;
+                    Some((year.parse().ok()?, day.parse().ok()?))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    solutions.sort();
+
+    let mut code = String::new();
+    for (year, day) in &solutions {
+        let path = Path::new(&manifest_dir).join(format!("src/bin/{}-{:02}/main.rs", year, day));
+        code += &format!(
+            "#[path = {:?}]\n#[allow(dead_code)]\nmod y{}_d{:02};\n",
+            path, year, day
+        );
+    }
+
+    code += "\npub const SOLUTIONS: &[advent_of_code::commands::Solution] = &[\n";
+    for (year, day) in &solutions {
+        code += &format!(
+            "    advent_of_code::commands::Solution {{ year: {}, day: advent_of_code::day!({}), run: y{}_d{:02}::run }},\n",
+            year, day, year, day
+        );
+    }
+    code += "];\n";
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), code).unwrap();
+}
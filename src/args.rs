@@ -1,6 +1,15 @@
+use std::{path::PathBuf, time::Duration};
+
 use clap::{Parser, Subcommand};
 
-use advent_of_code::commands::Day;
+use advent_of_code::commands::{
+    bench::{
+        BENCH_CPU_ENV_VAR, BENCH_ITERATIONS_ENV_VAR, BENCH_TIME_ENV_VAR, BENCH_WARMUP_ENV_VAR,
+        CPU_COUNT,
+    },
+    history::{DEFAULT_REGRESSION_THRESHOLD, REGRESSION_THRESHOLD_ENV_VAR},
+    Day, DayRange, Event, ReportFormat, REPORT_ENV_VAR,
+};
 
 #[derive(Parser)]
 pub struct Arguments {
@@ -9,7 +18,7 @@ pub struct Arguments {
 
     /// The target year (defaults to the last AoC event)
     #[arg(global = true, env = "AOC_YEAR")]
-    pub year: Option<u16>,
+    pub year: Option<Event>,
 }
 
 #[derive(Subcommand)]
@@ -22,21 +31,238 @@ pub enum Command {
         /// Download input from adventofcode.com (requires session cookie)
         #[arg(short, long)]
         download: bool,
+
+        /// The template of the module, a directory of `templates/`
+        #[arg(short, long, default_value = "default")]
+        template: String,
+
+        /// Only show which files would be created
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Overwrite the existing module and example files (and download the input again)
+        #[arg(short, long)]
+        force: bool,
     },
 
     /// Solve the puzzle of a given day
     Solve {
-        /// The day to solve (during an AoC event, defaults to the current day)
-        day: Option<Day>,
+        /// The day, or range of days (e.g. `1-5`), to solve (during an AoC event, defaults to the
+        /// current day)
+        days: Option<DayRange>,
+
+        /// Solve every day of the year and print a summary table
+        #[arg(short, long, conflicts_with = "days")]
+        all: bool,
 
         /// Run the solution in release mode
         #[arg(short, long)]
         release: bool,
+
+        /// Run the solution on an example (`data/examples/<year>/<day>-<N>.txt`) instead of the
+        /// puzzle input
+        #[arg(long, value_name = "N", conflicts_with = "input")]
+        example: Option<u8>,
+
+        /// Run the solution on a file instead of the puzzle input (`-` for the standard input)
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+
+        /// Output format of the results
+        #[arg(long, value_enum, env = REPORT_ENV_VAR, default_value_t)]
+        report: ReportFormat,
+
+        /// Highlight benchmark regressions above this threshold (in percent)
+        #[arg(long, env = REGRESSION_THRESHOLD_ENV_VAR, default_value_t = DEFAULT_REGRESSION_THRESHOLD)]
+        regression_threshold: f64,
+
+        /// Warm-up time of the benchmarks, in seconds
+        #[arg(long, env = BENCH_WARMUP_ENV_VAR, default_value_t = 0.5, value_parser = parse_seconds)]
+        warmup: f64,
+
+        /// Time budget of the benchmarks, in seconds
+        #[arg(long, env = BENCH_TIME_ENV_VAR, default_value_t = 5.0, value_parser = parse_seconds)]
+        bench_time: f64,
+
+        /// Maximum number of benchmark iterations
+        #[arg(long, env = BENCH_ITERATIONS_ENV_VAR, default_value_t = 10_000)]
+        bench_iterations: usize,
+
+        /// Pin the benchmarks to a CPU (Linux only)
+        #[arg(long, env = BENCH_CPU_ENV_VAR, value_parser = parse_cpu)]
+        pin: Option<usize>,
+
+        /// Give up on a part after this time (e.g. `90s`, `500ms`, `2m`, seconds without a
+        /// unit), the benchmarks stop once it is spent
+        #[arg(long, value_name = "DUR", value_parser = parse_duration)]
+        timeout: Option<Duration>,
+
+        /// Report the timing tree of the `span!`s of each part, measured on its first run
+        #[arg(long, conflicts_with = "all")]
+        profile: bool,
+
+        /// Solve the day again when its module, the library, or its examples and input change
+        #[arg(short, long, conflicts_with = "all")]
+        watch: bool,
+
+        /// Report the progress of the day as JSON events, in the child process of `--timeout`
+        #[arg(long, hide = true, conflicts_with_all = ["all", "watch"])]
+        events: bool,
+
+        /// Parts not to run, in the child process of `--timeout`
+        #[arg(long, hide = true, requires = "events")]
+        skip: Vec<u8>,
+    },
+
+    /// Run the example and input tests of a day, and summarize them by part
+    Test {
+        /// The day to test (during an AoC event, defaults to the current day)
+        day: Option<Day>,
+
+        /// Test every scaffolded day of the year
+        #[arg(short, long, conflicts_with = "day")]
+        all: bool,
+
+        /// Only run the example tests
+        #[arg(long, conflicts_with = "inputs_only")]
+        examples_only: bool,
+
+        /// Only run the input tests
+        #[arg(long)]
+        inputs_only: bool,
+    },
+
+    /// Show the puzzle description of a given day
+    Read {
+        /// The day to read (during an AoC event, defaults to the current day)
+        day: Option<Day>,
+
+        /// Download the puzzle again, even if it is cached
+        #[arg(long)]
+        refresh: bool,
+    },
+
+    /// Show which days are scaffolded, downloaded and solved
+    Status,
+
+    /// Show a private leaderboard
+    Leaderboard {
+        /// The id of the leaderboard (the number at the end of its URL)
+        id: u64,
+    },
+
+    /// Count down to the unlock of a puzzle, then scaffold it and download its input
+    Wait {
+        /// The day to wait for (defaults to the next day to unlock)
+        day: Option<Day>,
     },
 
     /// Download the puzzle input for a given day
     Download {
         /// The day to download input for (during an AoC event, defaults to the current day)
         day: Option<Day>,
+
+        /// Download the input again even if it was already downloaded
+        #[arg(short, long)]
+        force: bool,
     },
+
+    /// Submit an answer and record its verdict
+    Submit {
+        /// The day to submit an answer for
+        day: Day,
+
+        /// The puzzle part (1 or 2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// The answer to submit (defaults to the answer of the last `solve` run)
+        answer: Option<String>,
+    },
+
+    /// Record the answers of the last `solve` run as verified answers
+    Record {
+        /// The day to record answers for (during an AoC event, defaults to the current day)
+        day: Option<Day>,
+
+        /// Only record this part (1 or 2)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Replace answers that differ from the recorded ones
+        #[arg(short, long)]
+        force: bool,
+    },
+}
+
+/// Parses a number of seconds, which must fit in a [`Duration`] (finite and not negative).
+fn parse_seconds(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(seconds) if Duration::try_from_secs_f64(seconds).is_ok() => Ok(seconds),
+        _ => Err(format!(
+            "`{}` is not a non-negative number of seconds",
+            value
+        )),
+    }
+}
+
+/// Parses the CPU a benchmark is pinned to.
+fn parse_cpu(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(cpu) if cpu < CPU_COUNT => Ok(cpu),
+        Ok(_) => Err(format!("expecting a CPU below {}", CPU_COUNT)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Parses a duration with a `ms`, `s`, `m` or `h` unit, in seconds without one.
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: f64 = amount
+        .parse()
+        .map_err(|_| format!("invalid duration `{}`", value))?;
+    let seconds = match unit.trim() {
+        "ms" => amount / 1000.0,
+        "" | "s" => amount,
+        "m" => amount * 60.0,
+        "h" => amount * 3600.0,
+        unit => return Err(format!("unknown duration unit `{}`", unit)),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("duration `{}` is too long", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_seconds() {
+        assert_eq!(parse_seconds("0.5"), Ok(0.5));
+        assert_eq!(parse_seconds("0"), Ok(0.0));
+        assert!(parse_seconds("-1").is_err());
+        assert!(parse_seconds("NaN").is_err());
+        assert!(parse_seconds("inf").is_err());
+        assert!(parse_seconds("1e300").is_err());
+        assert!(parse_cpu(&CPU_COUNT.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("2d").is_err());
+        assert!(parse_duration("99999999999999999999999").is_err());
+        assert_eq!(
+            parse_duration("10000000000000000000s"),
+            Ok(Duration::from_secs(10_000_000_000_000_000_000))
+        );
+    }
 }
@@ -6,7 +6,7 @@ use advent_of_code::{
     parsing::parse_grid,
 };
 
-advent_of_code::solution!(2023, 17);
+advent_of_code::solution!(2023, 17, parse = parse_grid::<i32, i32>);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct Crucible {
@@ -96,14 +96,12 @@ fn path(grid: &HashMap<Coordinate<i32>, i32>, min_move: i32, max_move: i32) -> i
     0
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
-    let grid = parse_grid::<i32, i32>(input);
-    Some(path(&grid, 0, 3))
+pub fn part_one(grid: &HashMap<Coordinate<i32>, i32>) -> Option<i32> {
+    Some(path(grid, 0, 3))
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
-    let grid = parse_grid::<i32, i32>(input);
-    Some(path(&grid, 4, 10))
+pub fn part_two(grid: &HashMap<Coordinate<i32>, i32>) -> Option<i32> {
+    Some(path(grid, 4, 10))
 }
 
 #[cfg(test)]
@@ -113,19 +111,19 @@ mod tests {
 
     #[test]
     fn example_part_one() {
-        let result = part_one(&read_example(YEAR, DAY, 1));
+        let result = part_one(&parse_grid(&read_example(YEAR, DAY, 1)));
         assert_eq!(result, Some(102));
     }
 
     #[test]
     fn example_part_two() {
-        let result = part_two(&read_example(YEAR, DAY, 1));
+        let result = part_two(&parse_grid(&read_example(YEAR, DAY, 1)));
         assert_eq!(result, Some(94));
     }
 
     #[test]
     fn example_part_two2() {
-        let result = part_two(&read_example(YEAR, DAY, 1));
+        let result = part_two(&parse_grid(&read_example(YEAR, DAY, 1)));
         assert_eq!(result, Some(94));
     }
 }
@@ -262,7 +262,7 @@ pub fn part_two(input: &str) -> Option<u64> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use advent_of_code::commands::{read_example, read_input};
+    use advent_of_code::commands::{read_answer, read_example, read_input};
 
     #[test]
     fn example_part_one() {
@@ -273,7 +273,7 @@ mod tests {
     #[test]
     fn input_part_one() {
         let result = part_one(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(449531));
+        assert_eq!(result, read_answer(YEAR, DAY, 1));
     }
 
     #[test]
@@ -285,6 +285,6 @@ mod tests {
     #[test]
     fn input_part_two() {
         let result = part_two(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(122756210763577));
+        assert_eq!(result, read_answer(YEAR, DAY, 2));
     }
 }
@@ -334,7 +334,7 @@ pub fn part_two(input: &str) -> Option<u64> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use advent_of_code::commands::{read_example, read_input};
+    use advent_of_code::commands::{read_answer, read_example, read_input};
 
     #[test]
     fn example1_part_one() {
@@ -351,12 +351,12 @@ mod tests {
     #[test]
     fn input_part_one() {
         let result = part_one(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(949764474));
+        assert_eq!(result, read_answer(YEAR, DAY, 1));
     }
 
     #[test]
     fn input_part_two() {
         let result = part_two(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(243221023462303));
+        assert_eq!(result, read_answer(YEAR, DAY, 2));
     }
 }
@@ -112,7 +112,7 @@ pub fn part_two(input: &str) -> Option<i64> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use advent_of_code::commands::{read_example, read_input};
+    use advent_of_code::commands::{read_answer, read_example, read_input};
 
     #[test]
     fn example_part_one() {
@@ -123,12 +123,12 @@ mod tests {
     #[test]
     fn input_part_one() {
         let result = part_one(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(3830));
+        assert_eq!(result, read_answer(YEAR, DAY, 1));
     }
 
     #[test]
     fn input_part_two() {
         let result = part_two(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(637087163925555));
+        assert_eq!(result, read_answer(YEAR, DAY, 2));
     }
 }
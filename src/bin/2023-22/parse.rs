@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use advent_of_code::grid::Coordinate3D;
 use num::{Integer, Num};
 
-use crate::brick::Brick;
+use super::brick::Brick;
 
 /// Parse the input into a vector of bricks, sorted by `z` coordinate.
 pub(super) fn parse<T: Integer + Copy>(input: &str) -> Vec<Brick<T>>
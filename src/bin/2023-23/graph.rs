@@ -2,12 +2,13 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use advent_of_code::grid::{Coordinate, Direction};
 
-use crate::parse::Tile;
+use super::parse::Tile;
 
 pub(super) fn graph(
     grid: &HashMap<Coordinate<usize>, Tile>,
     slopes: bool,
 ) -> HashMap<Coordinate<usize>, HashMap<Coordinate<usize>, usize>> {
+    let _span = advent_of_code::span!("build graph");
     let mut graph: HashMap<Coordinate<usize>, HashMap<Coordinate<usize>, usize>> = HashMap::new();
 
     // Find all junctions
@@ -13,6 +13,7 @@ fn longest_path(
     graph: HashMap<Coordinate<usize>, HashMap<Coordinate<usize>, usize>>,
     max_y: usize,
 ) -> usize {
+    let _span = advent_of_code::span!("longest path");
     let mut longest_path = 0;
     let mut queue = VecDeque::new();
     queue.push_back(vec![Coordinate { x: 1, y: 0 }]);
@@ -60,7 +61,7 @@ pub fn part_two(input: &str) -> Option<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use advent_of_code::commands::{read_example, read_input};
+    use advent_of_code::commands::{read_answer, read_example, read_input};
 
     #[test]
     fn example_part_one() {
@@ -71,7 +72,7 @@ mod tests {
     #[test]
     fn input_part_one() {
         let result = part_one(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(2086));
+        assert_eq!(result, read_answer(YEAR, DAY, 1));
     }
 
     #[test]
@@ -83,6 +84,6 @@ mod tests {
     #[test]
     fn input_part_two() {
         let result = part_two(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(6526));
+        assert_eq!(result, read_answer(YEAR, DAY, 2));
     }
 }
@@ -10,6 +10,7 @@ pub(super) enum Tile {
 }
 
 pub(super) fn parse(input: &str) -> (HashMap<Coordinate<usize>, Tile>, usize) {
+    let _span = advent_of_code::span!("parse");
     let mut grid = HashMap::new();
     let mut max_x = 0;
     let mut max_y = 0;
@@ -1,4 +1,4 @@
-use crate::parse::Equation;
+use super::parse::Equation;
 
 #[derive(Clone, Copy)]
 enum Operator {
@@ -1,14 +1,14 @@
 mod logic;
 mod parse;
 
-advent_of_code::solution!(2024, 7);
+use parse::{parse, Equation};
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let equations = parse::parse(input);
+advent_of_code::solution!(2024, 7, parse = parse);
 
+pub fn part_one(equations: &[Equation]) -> Option<u64> {
     let mut sum = 0;
     for equation in equations {
-        if logic::solve_equation(&equation, false) {
+        if logic::solve_equation(equation, false) {
             sum += equation.result;
         }
     }
@@ -16,12 +16,10 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(sum)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let equations = parse::parse(input);
-
+pub fn part_two(equations: &[Equation]) -> Option<u64> {
     let mut sum = 0;
     for equation in equations {
-        if logic::solve_equation(&equation, true) {
+        if logic::solve_equation(equation, true) {
             sum += equation.result;
         }
     }
@@ -32,29 +30,29 @@ pub fn part_two(input: &str) -> Option<u64> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use advent_of_code::commands::{read_example, read_input};
+    use advent_of_code::commands::{read_answer, read_example, read_input};
 
     #[test]
     fn example_part_one() {
-        let result = part_one(&read_example(YEAR, DAY, 1));
+        let result = part_one(&parse(&read_example(YEAR, DAY, 1)));
         assert_eq!(result, Some(3749));
     }
 
     #[test]
     fn input_part_one() {
-        let result = part_one(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(3351424677624));
+        let result = part_one(&parse(&read_input(YEAR, DAY)));
+        assert_eq!(result, read_answer(YEAR, DAY, 1));
     }
 
     #[test]
     fn example_part_two() {
-        let result = part_two(&read_example(YEAR, DAY, 1));
+        let result = part_two(&parse(&read_example(YEAR, DAY, 1)));
         assert_eq!(result, Some(11387));
     }
 
     #[test]
     fn input_part_two() {
-        let result = part_two(&read_input(YEAR, DAY));
-        assert_eq!(result, Some(204976636995111));
+        let result = part_two(&parse(&read_input(YEAR, DAY)));
+        assert_eq!(result, read_answer(YEAR, DAY, 2));
     }
 }
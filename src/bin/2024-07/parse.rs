@@ -1,5 +1,5 @@
 #[derive(Clone)]
-pub(super) struct Equation {
+pub struct Equation {
     pub result: u64,
     pub operands: Vec<u64>,
 }
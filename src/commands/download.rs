@@ -1,8 +1,9 @@
-use std::fs::{create_dir_all, File, OpenOptions};
+use std::fs::{create_dir_all, metadata, File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 use std::process;
 
+use super::checksum::Manifest;
 use super::utils::aoc::get_input;
 use super::Day;
 
@@ -18,23 +19,41 @@ fn create_file_and_dirs(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(year: u16, day: Day) {
-    let input = match get_input(year, day) {
-        Ok(input) => input,
-        Err(e) => {
-            eprintln!("🫎 Failed to download input: {}", e);
-            process::exit(1);
-        }
-    };
-
+/// Downloads the input of a day and records its checksum, returning what was done.
+///
+/// An input that is already downloaded (non-empty) is only downloaded again with `force`.
+pub fn download(year: u16, day: Day, force: bool) -> Result<String, String> {
     let input_file = format!("data/inputs/{}/{}.txt", year, day);
-    match create_file_and_dirs(&input_file) {
-        Ok(mut file) => file.write_all(input.as_bytes()).unwrap(),
+    if !force && metadata(&input_file).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(format!(
+            "Input already downloaded: {} (use --force to download it again)",
+            input_file
+        ));
+    }
+
+    let input =
+        get_input(year, day, force).map_err(|e| format!("Failed to download input: {}", e))?;
+    create_file_and_dirs(&input_file)
+        .and_then(|mut file| file.write_all(input.as_bytes()))
+        .map_err(|e| format!("Failed to write to input file: {}", e))?;
+
+    let recorded = Manifest::load(year).and_then(|mut manifest| {
+        manifest.insert(day, input.as_bytes());
+        manifest.save()
+    });
+    if let Err(e) = recorded {
+        eprintln!("🫎 Failed to record input checksum: {}", e);
+    }
+
+    Ok(format!("Downloaded input into file: {}", input_file))
+}
+
+pub fn handle(year: u16, day: Day, force: bool) {
+    match download(year, day, force) {
+        Ok(message) => println!("🎄 {}", message),
         Err(e) => {
-            eprintln!("🫎 Failed to write to input file: {}", e);
+            eprintln!("🫎 {}", e);
             process::exit(1);
         }
     }
-
-    println!("🎄 Downloaded input into file: {}", input_file);
 }
@@ -0,0 +1,197 @@
+use std::{collections::BTreeMap, process};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::aoc::{get_leaderboard_json, unlock_time};
+use super::{Day, Event};
+
+/// A private leaderboard, as served by `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Debug, Deserialize)]
+struct Leaderboard {
+    owner_id: u64,
+    members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Member {
+    id: u64,
+    name: Option<String>,
+    stars: u32,
+    local_score: u32,
+    last_star_ts: i64,
+    /// The stars of each day, by day and part.
+    completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Star {
+    get_star_ts: i64,
+}
+
+impl Member {
+    fn name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Returns the time of the last star of a day, relative to its unlock, and whether the day has
+    /// both stars.
+    fn completion(&self, year: u16, day: Day) -> Option<(i64, bool)> {
+        let parts = self.completion_day_level.get(&u8::from(day))?;
+        let (part, star) = parts.iter().next_back()?;
+        let time = DateTime::<Utc>::from_timestamp(star.get_star_ts, 0)?;
+        Some(((time - unlock_time(year, day)).num_seconds(), *part == 2))
+    }
+}
+
+/// Formats a completion time like adventofcode.com, which does not detail times after a day.
+fn format_time(seconds: i64) -> String {
+    if seconds >= 24 * 3600 {
+        ">24h".to_string()
+    } else {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
+
+/// Renders the members of a leaderboard by local score, with the stars and completion time of
+/// every day that has at least one star.
+fn render(leaderboard: &Leaderboard, event: Event) -> Vec<String> {
+    let year = event.year();
+    let mut members: Vec<&Member> = leaderboard.members.values().collect();
+    members.sort_by_key(|member| {
+        (
+            u32::MAX - member.local_score,
+            u32::MAX - member.stars,
+            member.last_star_ts,
+        )
+    });
+    let days: Vec<Day> = event
+        .days()
+        .filter(|day| {
+            members
+                .iter()
+                .any(|member| member.completion_day_level.contains_key(&u8::from(*day)))
+        })
+        .collect();
+
+    let mut headers = vec![
+        "Rank".to_string(),
+        "Member".to_string(),
+        "Score".to_string(),
+        "Stars".to_string(),
+    ];
+    headers.extend(days.iter().map(|day| format!("Day {}", day)));
+    let rows: Vec<Vec<String>> = members
+        .iter()
+        .enumerate()
+        .map(|(rank, member)| {
+            let mut row = vec![
+                format!("{}", rank + 1),
+                member.name(),
+                member.local_score.to_string(),
+                member.stars.to_string(),
+            ];
+            row.extend(days.iter().map(|day| match member.completion(year, *day) {
+                Some((time, true)) => format!("★ {}", format_time(time)),
+                Some((time, false)) => format!("☆ {}", format_time(time)),
+                None => String::new(),
+            }));
+            row
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|column| {
+            rows.iter()
+                .chain([&headers])
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect();
+    let format_row = |row: &[String]| {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (value, width))| match column {
+                // Names and stars are left-aligned, numbers right-aligned.
+                1 | 4.. => format!("{:<width$}", value, width = width),
+                _ => format!("{:>width$}", value, width = width),
+            })
+            .collect::<Vec<_>>()
+            .join(" | ");
+        line.trim_end_matches([' ', '|']).to_string()
+    };
+
+    let mut lines = vec![format_row(&headers)];
+    lines.push(
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-+-"),
+    );
+    lines.extend(rows.iter().map(|row| format_row(row)));
+    lines
+}
+
+/// Shows a private leaderboard. Its JSON is cached for 15 minutes, as adventofcode.com asks.
+pub fn handle(event: Event, id: u64) {
+    let year = event.year();
+    let leaderboard: Leaderboard =
+        match get_leaderboard_json(year, id).map(|json| serde_json::from_str(&json)) {
+            Ok(Ok(leaderboard)) => leaderboard,
+            Ok(Err(e)) => {
+                eprintln!("🫎 Failed to parse leaderboard: {}", e);
+                process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("🫎 Failed to download leaderboard: {}", e);
+                process::exit(1);
+            }
+        };
+
+    let owner = leaderboard
+        .members
+        .get(&leaderboard.owner_id.to_string())
+        .map_or("?".to_string(), Member::name);
+    println!("🎄 Private leaderboard of {} for {}", owner, year);
+    println!();
+    for line in render(&leaderboard, event) {
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let leaderboard: Leaderboard = serde_json::from_str(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/leaderboard.json"
+        )))
+        .unwrap();
+
+        assert_eq!(
+            render(&leaderboard, Event::new(2015).unwrap()),
+            [
+                "Rank | Member                | Score | Stars | Day 01    | Day 02    | Day 03",
+                "-----+-----------------------+-------+-------+-----------+-----------+----------",
+                "   1 | Alice                 |    14 |     5 | ★ 0:12:00 | ★ 0:25:00 | ☆ 0:30:00",
+                "   2 | Bob                   |     7 |     3 | ★ >24h    | ☆ 1:23:20",
+                "   3 | (anonymous user #103) |     0 |     0",
+            ]
+        );
+    }
+}
@@ -1,6 +1,14 @@
 pub mod download;
+pub mod leaderboard;
+pub mod read;
+pub mod record;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
+pub mod submit;
+pub mod test;
 pub mod utils;
+pub mod wait;
+pub mod watch;
 
 pub use utils::*;
@@ -0,0 +1,18 @@
+use std::process;
+
+use super::puzzle::{get_puzzle, render};
+use super::Day;
+
+/// Width the puzzle description is wrapped to.
+const WIDTH: usize = 80;
+
+/// Shows the puzzle description of a day (see [`get_puzzle`] for the caching).
+pub fn handle(year: u16, day: Day, refresh: bool) {
+    match get_puzzle(year, day, refresh) {
+        Ok(html) => println!("{}", render(&html, WIDTH)),
+        Err(e) => {
+            eprintln!("🫎 Failed to download puzzle: {}", e);
+            process::exit(1);
+        }
+    }
+}
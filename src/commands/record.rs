@@ -0,0 +1,55 @@
+use std::process;
+
+use super::answers::AnswerStore;
+use super::{read_last_answer, Day};
+
+/// Records the answers of the last `solve` run of a day into the answer store.
+///
+/// An answer that differs from the recorded one is only replaced with `force`.
+pub fn handle(year: u16, day: Day, part: Option<u8>, force: bool) {
+    let mut store = AnswerStore::load(year).unwrap_or_else(|e| {
+        eprintln!("🫎 Failed to read answer store: {}", e);
+        process::exit(1);
+    });
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut changed = false;
+    for part in parts {
+        let Some(answer) = read_last_answer(year, day, part) else {
+            eprintln!(
+                "🫎 No previous run found for Day {} Part {}, run `solve` first",
+                day, part
+            );
+            continue;
+        };
+        let answer = answer.trim().to_string();
+
+        match store.get(day, part) {
+            Some(recorded) if recorded == answer => {
+                println!("🎄 Day {} Part {}: {} already recorded", day, part, answer);
+            }
+            Some(recorded) if !force => {
+                eprintln!(
+                    "🫎 Day {} Part {}: {} differs from the recorded answer {}, use --force to replace it",
+                    day, part, answer, recorded
+                );
+            }
+            _ => {
+                println!("🎄 Recorded Day {} Part {}: {}", day, part, answer);
+                store.insert(day, part, answer);
+                changed = true;
+            }
+        }
+    }
+
+    if changed {
+        if let Err(e) = store.save() {
+            eprintln!("🫎 Failed to write answer store: {}", e);
+            process::exit(1);
+        }
+    }
+}
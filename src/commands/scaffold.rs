@@ -1,5 +1,6 @@
 use std::{
-    fs::{create_dir_all, File, OpenOptions},
+    fmt::Display,
+    fs::{create_dir_all, metadata, read_dir, read_to_string, File, OpenOptions},
     io::Write,
     path::Path,
     process,
@@ -8,12 +9,33 @@ use std::{
 use crate::commands::Day;
 
 use super::download;
+use super::puzzle::{cached_puzzle, extract_examples, get_puzzle, title, Examples};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// The directory of the module templates, one directory of files per template.
+const TEMPLATES_DIR: &str = "templates";
 
-fn create_file_safe(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Options of the `scaffold` command.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Download the puzzle page and the input.
+    pub download: bool,
+    /// Name of the template directory, in `templates/`.
+    pub template: String,
+    /// Only report what would be created.
+    pub dry_run: bool,
+    /// Overwrite the existing files.
+    pub force: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            download: false,
+            template: "default".to_string(),
+            dry_run: false,
+            force: false,
+        }
+    }
 }
 
 fn create_file_and_dirs(path: &str) -> Result<File, std::io::Error> {
@@ -28,62 +50,350 @@ fn create_file_and_dirs(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(year: u16, day: Day, download: bool) {
-    let module_dir = format!("src/bin/{}-{}", year, day);
-    let module_file = format!("{}/main.rs", module_dir);
-    let input_file = format!("data/inputs/{}/{}.txt", year, day);
-    let example_file = format!("data/examples/{}/{}-1.txt", year, day);
+/// The overall shape of an input, guessed to pick a template or a parsing strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputShape {
+    Unknown,
+    SingleLine(usize),
+    Grid { width: usize, height: usize },
+    Blocks(usize),
+    Lines(usize),
+}
+
+impl InputShape {
+    fn detect(input: &str) -> Self {
+        let input = input.trim_end_matches('\n');
+        let lines: Vec<&str> = input.lines().collect();
+        match lines.as_slice() {
+            [] => Self::Unknown,
+            [line] => Self::SingleLine(line.chars().count()),
+            _ if input.contains("\n\n") => Self::Blocks(input.split("\n\n").count()),
+            [first, ..]
+                if first.chars().count() > 1
+                    && lines.iter().all(|line| {
+                        line.chars().count() == first.chars().count()
+                            && !line.contains(char::is_whitespace)
+                    }) =>
+            {
+                Self::Grid {
+                    width: first.chars().count(),
+                    height: lines.len(),
+                }
+            }
+            _ => Self::Lines(lines.len()),
+        }
+    }
+}
+
+impl Display for InputShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("unknown"),
+            Self::SingleLine(length) => write!(f, "a single line of {} characters", length),
+            Self::Grid { width, height } => write!(f, "a {}x{} grid", width, height),
+            Self::Blocks(count) => write!(f, "{} blocks of lines", count),
+            Self::Lines(count) => write!(f, "{} lines", count),
+        }
+    }
+}
+
+/// Values of the placeholders of the templates.
+struct Context<'a> {
+    year: u16,
+    day: Day,
+    title: Option<String>,
+    shape: InputShape,
+    examples: &'a Examples,
+}
 
-    // Create the module directory if it doesn't exist.
-    create_dir_all(module_dir).unwrap_or_else(|e| {
-        eprintln!("🫎 Failed to create module directory: {}", e);
+/// Fills the placeholders of a template file: `%YEAR%`, `%DAY%`, `%DAY_PADDED%`, `%TITLE%`,
+/// `%INPUT_SHAPE%`, and the example number and expected answer of each part (`%EXAMPLE_N%` and
+/// `%ANSWER_N%`).
+///
+/// Expected answers that do not fit the `u32` of the templates are only printed.
+fn fill_template(template: &str, context: &Context) -> String {
+    let mut module = template
+        .replace("%YEAR%", &context.year.to_string())
+        .replace("%DAY_PADDED%", &context.day.to_string())
+        .replace("%DAY%", &u8::from(context.day).to_string())
+        .replace(
+            "%TITLE%",
+            context.title.as_deref().unwrap_or("Unknown title"),
+        )
+        .replace("%INPUT_SHAPE%", &context.shape.to_string());
+
+    for (part, answer) in context.examples.answers.iter().enumerate() {
+        let (example, expected) = match answer {
+            Some((example, answer)) if answer.parse::<u32>().is_ok() => {
+                (*example, format!("Some({})", answer))
+            }
+            Some((example, answer)) => {
+                if module.contains(&format!("%ANSWER_{}%", part + 1)) {
+                    println!(
+                        "🎄 Expected answer of part {} on example {}: {}",
+                        part + 1,
+                        example,
+                        answer
+                    );
+                }
+                (*example, "None".to_string())
+            }
+            None => (1, "None".to_string()),
+        };
+        module = module
+            .replace(&format!("%EXAMPLE_{}%", part + 1), &example.to_string())
+            .replace(&format!("%ANSWER_{}%", part + 1), &expected);
+    }
+    module
+}
+
+/// Reads the files of a template, by file name.
+fn read_template(name: &str) -> Vec<(String, String)> {
+    let dir = Path::new(TEMPLATES_DIR).join(name);
+    let entries = read_dir(&dir).unwrap_or_else(|_| {
+        let mut available: Vec<String> = read_dir(TEMPLATES_DIR)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.file_name().to_string_lossy().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        available.sort();
+        eprintln!(
+            "🫎 Template `{}` not found in {}/ (available: {})",
+            name,
+            TEMPLATES_DIR,
+            available.join(", ")
+        );
         process::exit(1);
     });
 
-    // Create the module file if it doesn't exist.
-    if !Path::new(&module_file).exists() {
-        let mut file = match create_file_safe(&module_file) {
-            Ok(file) => file,
-            Err(e) => {
-                eprintln!("🫎 Failed to create module file: {}", e);
+    let mut files: Vec<(String, String)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| {
+            let content = read_to_string(entry.path()).unwrap_or_else(|e| {
+                eprintln!("🫎 Failed to read template file: {}", e);
                 process::exit(1);
-            }
-        };
+            });
+            (entry.file_name().to_string_lossy().to_string(), content)
+        })
+        .collect();
+    files.sort();
 
-        match file.write_all(
-            MODULE_TEMPLATE
-                .replace("%YEAR%", &year.to_string())
-                .replace("%DAY%", &u8::from(day).to_string())
-                .as_bytes(),
-        ) {
-            Ok(_) => println!("🎄 Created module file: {}", module_file),
-            Err(e) => {
-                eprintln!("🫎 Failed to write to module file: {}", e);
+    if !files.iter().any(|(filename, _)| filename == "main.rs") {
+        eprintln!("🫎 Template `{}` has no main.rs", name);
+        process::exit(1);
+    }
+    files
+}
+
+/// What happened to a file of the scaffold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Created,
+    Overwritten,
+    Skipped,
+}
+
+/// Writes a file of the scaffold unless it exists (and `--force` is not given), or only reports
+/// what would be done with `--dry-run`.
+///
+/// A file that is not empty is never replaced by an empty one, even with `--force`, to keep a
+/// pasted input or example.
+fn write_file(kind: &str, path: &str, content: &str, options: &Options) -> Outcome {
+    let exists = Path::new(path).exists();
+    let filled = metadata(path).is_ok_and(|metadata| metadata.len() > 0);
+    let outcome = match (exists, options.force) {
+        (false, _) => Outcome::Created,
+        (true, true) if content.is_empty() && filled => Outcome::Skipped,
+        (true, true) => Outcome::Overwritten,
+        (true, false) => Outcome::Skipped,
+    };
+
+    match (outcome, options.dry_run) {
+        (Outcome::Skipped, _) => println!("🎄 Skipped existing {} file: {}", kind, path),
+        (Outcome::Created, true) => println!("🎄 Would create {} file: {}", kind, path),
+        (Outcome::Overwritten, true) => println!("🎄 Would overwrite {} file: {}", kind, path),
+        (outcome, false) => {
+            if let Err(e) =
+                create_file_and_dirs(path).and_then(|mut file| file.write_all(content.as_bytes()))
+            {
+                eprintln!("🫎 Failed to write to {} file: {}", kind, e);
                 process::exit(1);
             }
+            match outcome {
+                Outcome::Created => println!("🎄 Created {} file: {}", kind, path),
+                _ => println!("🎄 Overwrote {} file: {}", kind, path),
+            }
         }
     }
 
-    // Create the example directory and file.
-    match create_file_and_dirs(&example_file) {
-        Ok(_) => println!("🎄 Created example file: {}", example_file),
-        Err(e) => {
-            eprintln!("🫎 Failed to create example file: {}", e);
-            process::exit(1);
-        }
-    }
+    outcome
+}
 
-    // Download if requested
-    if download {
-        download::handle(year, day);
+/// Creates the module, example and input files of a day that don't exist yet.
+///
+/// With `force`, existing module and example files are overwritten and the input is downloaded
+/// again, but an input or example file is never replaced by an empty one.
+pub fn handle(year: u16, day: Day, options: &Options) {
+    let module_dir = format!("src/bin/{}-{}", year, day);
+    let input_file = format!("data/inputs/{}/{}.txt", year, day);
+    let example_file = |number: u8| format!("data/examples/{}/{}-{}.txt", year, day, number);
+    let template = read_template(&options.template);
+    let download = options.download && !options.dry_run;
+
+    // Use the puzzle page to fill the examples, downloading it if requested.
+    let puzzle = if download {
+        get_puzzle(year, day, options.force)
+            .map_err(|e| eprintln!("🫎 Failed to download puzzle: {}", e))
+            .ok()
     } else {
-        // Create the input directory and file.
-        match create_file_and_dirs(&input_file) {
-            Ok(_) => println!("🎄 Created input file: {}", input_file),
+        cached_puzzle(year, day)
+    };
+    let examples = puzzle.as_deref().map(extract_examples).unwrap_or_default();
+
+    // Download first if requested, for the shape of the input to be known. The files are still
+    // created if the download fails, before exiting with an error.
+    let downloaded = !download
+        || match download::download(year, day, options.force) {
+            Ok(message) => {
+                println!("🎄 {}", message);
+                true
+            }
             Err(e) => {
-                eprintln!("🫎 Failed to create input file: {}", e);
-                process::exit(1);
+                eprintln!("🫎 {}", e);
+                false
             }
-        }
+        };
+    let input = read_to_string(&input_file)
+        .ok()
+        .filter(|input| !input.is_empty())
+        .or(examples.blocks.first().cloned())
+        .unwrap_or_default();
+    let context = Context {
+        year,
+        day,
+        title: puzzle.as_deref().and_then(title),
+        shape: InputShape::detect(&input),
+        examples: &examples,
+    };
+
+    let mut outcomes = Vec::new();
+    for (filename, content) in &template {
+        let module_file = format!("{}/{}", module_dir, filename);
+        outcomes.push(write_file(
+            "module",
+            &module_file,
+            &fill_template(content, &context),
+            options,
+        ));
+    }
+
+    // Create the example files, an empty one if no example was found.
+    let blocks = match examples.blocks.as_slice() {
+        [] => &[String::new()][..],
+        blocks => blocks,
+    };
+    for (index, block) in blocks.iter().enumerate() {
+        let example_file = example_file(index as u8 + 1);
+        outcomes.push(write_file("example", &example_file, block, options));
+    }
+
+    if options.download && options.dry_run {
+        println!("🎄 Would download input file: {}", input_file);
+    } else if !options.download {
+        // An empty input file to paste the input into.
+        outcomes.push(write_file("input", &input_file, "", options));
+    }
+
+    let count = |outcome| outcomes.iter().filter(|o| **o == outcome).count();
+    if options.dry_run {
+        println!(
+            "🎄 Dry run: {} file(s) to create, {} to overwrite, {} skipped",
+            count(Outcome::Created),
+            count(Outcome::Overwritten),
+            count(Outcome::Skipped)
+        );
+    } else {
+        println!(
+            "🎄 {} file(s) created, {} overwritten, {} skipped",
+            count(Outcome::Created),
+            count(Outcome::Overwritten),
+            count(Outcome::Skipped)
+        );
+    }
+    if !downloaded {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_template() {
+        let examples = Examples {
+            blocks: vec!["#.#\n.#.\n".to_string()],
+            answers: [Some((1, "5".to_string())), None],
+        };
+        let context = Context {
+            year: 2024,
+            day: Day::new(3).unwrap(),
+            title: Some("Sock Drawer".to_string()),
+            shape: InputShape::detect(&examples.blocks[0]),
+            examples: &examples,
+        };
+
+        assert_eq!(
+            fill_template(
+                "// %DAY_PADDED%/%DAY%: %TITLE% (%INPUT_SHAPE%)\n%EXAMPLE_1% %ANSWER_1% %ANSWER_2%",
+                &context
+            ),
+            "// 03/3: Sock Drawer (a 3x2 grid)\n1 Some(5) None"
+        );
+        assert_eq!(InputShape::detect("1 2\n3 4\n"), InputShape::Lines(2));
+        assert_eq!(InputShape::detect("a\nb\n\nc"), InputShape::Blocks(2));
+        assert_eq!(InputShape::detect("abc\n"), InputShape::SingleLine(3));
+    }
+
+    #[test]
+    fn test_write_file() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let path = dir.join("01-1.txt").to_string_lossy().to_string();
+        let options = |dry_run, force| Options {
+            dry_run,
+            force,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            write_file("example", &path, "a", &options(true, false)),
+            Outcome::Created
+        );
+        assert!(!Path::new(&path).exists());
+        assert_eq!(
+            write_file("example", &path, "a", &options(false, false)),
+            Outcome::Created
+        );
+        assert_eq!(
+            write_file("example", &path, "b", &options(false, false)),
+            Outcome::Skipped
+        );
+        assert_eq!(read_to_string(&path).unwrap(), "a");
+        assert_eq!(
+            write_file("example", &path, "b", &options(false, true)),
+            Outcome::Overwritten
+        );
+        assert_eq!(read_to_string(&path).unwrap(), "b");
+        assert_eq!(
+            write_file("example", &path, "", &options(false, true)),
+            Outcome::Skipped
+        );
+        assert_eq!(read_to_string(&path).unwrap(), "b");
+
+        std::fs::remove_dir_all(dir).unwrap();
     }
 }
@@ -1,24 +1,751 @@
-use std::process::{Command, Stdio};
+use std::{
+    env,
+    io::{self, BufRead, BufReader, Write},
+    panic::{catch_unwind, AssertUnwindSafe},
+    process::{self, ChildStdout, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
 
-use super::Day;
+use serde::{Deserialize, Serialize};
 
-pub fn handle(year: u16, day: Day, release: bool) {
-    let mut args = vec![
-        "run".to_string(),
-        "--bin".to_string(),
-        format!("{}-{}", year, day),
-    ];
+use super::{
+    bench::BenchConfig,
+    history::delta,
+    profile::{self, SpanTiming},
+    Day, DayRange, Event, InputSource, PartResult, ReportFormat, Reporter, RunOptions, Solution,
+    PARSE_PART,
+};
 
-    if release {
-        args.push("--release".to_string());
+/// Options of the `solve` command.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Benchmark the solutions.
+    pub release: bool,
+    pub report: ReportFormat,
+    /// Benchmark regressions above this threshold (in percent) are highlighted.
+    pub regression_threshold: f64,
+    pub bench: BenchConfig,
+    /// Input given to the solutions.
+    pub source: InputSource,
+    /// Report the timing tree of the spans of each part.
+    pub profile: bool,
+    /// Time budget of each part.
+    pub timeout: Option<Duration>,
+}
+
+impl Options {
+    fn run_options(&self) -> RunOptions {
+        RunOptions {
+            source: self.source.clone(),
+            bench: self.release.then_some(self.bench),
+            profile: self.profile,
+            timeout: self.timeout,
+            skip: Vec::new(),
+        }
+    }
+
+    /// Returns the arguments of the `solve` command of a day with these options, e.g. for a child
+    /// process.
+    pub fn args(&self, year: u16, day: Day) -> Vec<String> {
+        let mut args = vec![year.to_string(), "solve".to_string(), day.to_string()];
+        let mut option = |name: &str, value: String| args.extend([name.to_string(), value]);
+        match &self.source {
+            InputSource::Input => {}
+            InputSource::Example(number) => option("--example", number.to_string()),
+            InputSource::File(path) => option("--input", path.display().to_string()),
+            InputSource::Stdin => option("--input", "-".to_string()),
+        }
+        option("--report", self.report.to_string());
+        option(
+            "--regression-threshold",
+            self.regression_threshold.to_string(),
+        );
+        option("--warmup", self.bench.warmup.as_secs_f64().to_string());
+        option(
+            "--bench-time",
+            self.bench.time_budget.as_secs_f64().to_string(),
+        );
+        option("--bench-iterations", self.bench.max_iterations.to_string());
+        if let Some(cpu) = self.bench.cpu {
+            option("--pin", cpu.to_string());
+        }
+        if let Some(timeout) = self.timeout {
+            option("--timeout", format!("{}s", timeout.as_secs_f64()));
+        }
+        if self.release {
+            args.push("--release".to_string());
+        }
+        if self.profile {
+            args.push("--profile".to_string());
+        }
+        args
+    }
+
+    fn warn_debug_build(&self) {
+        if self.release && cfg!(debug_assertions) {
+            eprintln!("🫎 Benchmarking a debug build, use `cargo solve` to build in release mode");
+        }
+    }
+}
+
+fn find(solutions: &[Solution], year: u16, day: Day) -> Option<&Solution> {
+    solutions
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+}
+
+pub fn handle(year: u16, day: Day, options: &Options, solutions: &[Solution]) {
+    let Some(solution) = find(solutions, year, day) else {
+        eprintln!("🫎 No solution found for day {} of {}", day, year);
+        process::exit(1);
+    };
+    let input = options.source.read(year, day).unwrap_or_else(|e| {
+        eprintln!("🫎 Could not open input file: {}", e);
+        process::exit(1);
+    });
+
+    if options.profile && !profile::enabled() {
+        eprintln!(
+            "🫎 Spans are only recorded with the `spans` feature, use `cargo run --release --features spans -- solve {} --profile`",
+            day
+        );
+        process::exit(1);
+    }
+
+    options.warn_debug_build();
+    let mut reporter = options.report.reporter(options.regression_threshold);
+    let child = |skip: &[u8]| child_command(year, day, options, skip);
+    let failed = run_solution(solution, &input, options, reporter.as_mut(), &child);
+    reporter.finish();
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Starts the events in the output of a child process, which may follow text printed by the
+/// solution on the same line.
+const EVENT_MARKER: char = '\x1e';
+
+/// The progress of a solution running in a child process, one JSON object per line of its
+/// standard output (after [`EVENT_MARKER`]).
+#[derive(Serialize, Deserialize)]
+enum Message {
+    Started(u8),
+    Solved(PartResult),
+    Finished(PartResult),
+    Profiled(PartResult, Vec<SpanTiming>, Duration),
+}
+
+impl Message {
+    fn part(&self) -> u8 {
+        match self {
+            Message::Started(part) => *part,
+            Message::Solved(result) | Message::Finished(result) | Message::Profiled(result, ..) => {
+                result.part
+            }
+        }
+    }
+}
+
+/// Writes the reports of a solution to the standard output, for the parent process to read.
+struct Events;
+
+impl Events {
+    fn send(&self, message: &Message) {
+        let mut stdout = io::stdout().lock();
+        let sent = write!(stdout, "{}", EVENT_MARKER)
+            .and_then(|_| serde_json::to_writer(&mut stdout, message).map_err(io::Error::from))
+            .and_then(|_| writeln!(stdout))
+            .and_then(|_| stdout.flush());
+        if let Err(e) = sent {
+            eprintln!("🫎 Failed to report to the parent process: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+impl Reporter for Events {
+    fn part_started(&mut self, _year: u16, _day: Day, part: u8) {
+        self.send(&Message::Started(part));
+    }
+
+    fn part_solved(&mut self, result: &PartResult) {
+        self.send(&Message::Solved(result.clone()));
+    }
+
+    fn part_finished(&mut self, result: &PartResult) {
+        self.send(&Message::Finished(result.clone()));
+    }
+
+    fn part_profiled(&mut self, result: &PartResult, timings: &[SpanTiming], total: Duration) {
+        self.send(&Message::Profiled(result.clone(), timings.to_vec(), total));
+    }
+}
+
+/// Runs a solution on the input given on the standard input, reporting its progress as events on
+/// the standard output.
+fn run_events(solution: &Solution, options: &RunOptions) {
+    let input = io::read_to_string(io::stdin()).unwrap_or_else(|e| {
+        eprintln!("🫎 Could not read input from the parent process: {}", e);
+        process::exit(1);
+    });
+    (solution.run)(&input, &mut Events, options);
+}
+
+/// Runs a day in the child process of a `solve --timeout` (with the hidden `--events` flag),
+/// skipping the given parts.
+pub fn handle_events(year: u16, day: Day, options: &Options, skip: &[u8], solutions: &[Solution]) {
+    let Some(solution) = find(solutions, year, day) else {
+        eprintln!("🫎 No solution found for day {} of {}", day, year);
+        process::exit(1);
+    };
+    let options = RunOptions {
+        skip: skip.to_vec(),
+        ..options.run_options()
+    };
+    run_events(solution, &options);
+}
+
+/// The command running a day in a child process, skipping the given parts.
+fn child_command(year: u16, day: Day, options: &Options, skip: &[u8]) -> Command {
+    let exe = env::current_exe().unwrap_or_else(|e| {
+        eprintln!("🫎 Could not find the current executable: {}", e);
+        process::exit(1);
+    });
+    let mut command = Command::new(exe);
+    command.args(options.args(year, day)).arg("--events");
+    for part in skip {
+        command.args(["--skip", &part.to_string()]);
+    }
+    command
+}
+
+/// Reads the events of a child process on another thread, which ends with the process. Lines
+/// that are not events (e.g. printed by the solution) are printed as they come.
+fn events(stdout: ChildStdout) -> Receiver<Message> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let Some((text, event)) = line.split_once(EVENT_MARKER) else {
+                println!("{}", line);
+                continue;
+            };
+            print!("{}", text);
+            match serde_json::from_str(event) {
+                Ok(message) => {
+                    if sender.send(message).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => println!("{}", event),
+            }
+        }
+    });
+    receiver
+}
+
+/// Runs a solution, and returns whether it panicked or one of its parts timed out.
+///
+/// With a timeout, the solution runs in a child process given by `child` (the skipped parts being
+/// its argument), and a part that has not been solved in time is reported as timed out. The
+/// process is killed, and the following parts are run in a new one (after parsing the input
+/// again).
+fn run_solution(
+    solution: &Solution,
+    input: &str,
+    options: &Options,
+    reporter: &mut dyn Reporter,
+    child: &dyn Fn(&[u8]) -> Command,
+) -> bool {
+    let Some(timeout) = options.timeout else {
+        let run_options = options.run_options();
+        return catch_unwind(AssertUnwindSafe(|| {
+            (solution.run)(input, reporter, &run_options)
+        }))
+        .is_err();
+    };
+
+    let mut finished = Vec::new();
+    let mut timed_out = false;
+    loop {
+        let spawned = child(&finished)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn();
+        let mut process = match spawned {
+            Ok(process) => process,
+            Err(e) => {
+                eprintln!("🫎 Failed to run the solution in a child process: {}", e);
+                return true;
+            }
+        };
+        // The child reads its whole input before reporting anything, and the pipe is closed once
+        // written.
+        if let Some(mut stdin) = process.stdin.take() {
+            let _ = stdin.write_all(input.as_bytes());
+        }
+        let receiver = events(process.stdout.take().unwrap());
+        // Parts reported by a previous process (i.e. the parsing step) are not reported again.
+        let reported = finished.clone();
+
+        // The part being run until its first run is over, and its deadline (none if the timeout
+        // is too far in the future to be represented).
+        let mut running: Option<(u8, Option<Instant>)> = None;
+        loop {
+            let message = match running {
+                Some((_, Some(deadline))) => {
+                    receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+                _ => receiver.recv().map_err(RecvTimeoutError::from),
+            };
+            match message {
+                Ok(message) => {
+                    running = match message {
+                        Message::Started(part) => Some((part, Instant::now().checked_add(timeout))),
+                        _ => None,
+                    };
+                    if reported.contains(&message.part()) {
+                        continue;
+                    }
+                    match message {
+                        Message::Started(part) => {
+                            reporter.part_started(solution.year, solution.day, part)
+                        }
+                        Message::Solved(result) => reporter.part_solved(&result),
+                        Message::Finished(result) => {
+                            finished.push(result.part);
+                            reporter.part_finished(&result);
+                        }
+                        Message::Profiled(result, timings, total) => {
+                            reporter.part_profiled(&result, &timings, total)
+                        }
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    let _ = process.kill();
+                    let _ = process.wait();
+                    let (part, _) = running.unwrap();
+                    let error = format!("timed out after {:.1?}", timeout);
+                    reporter.part_finished(&PartResult::failed(
+                        solution.year,
+                        solution.day,
+                        part,
+                        &error,
+                    ));
+                    timed_out = true;
+                    if part == PARSE_PART {
+                        return true;
+                    }
+                    finished.push(part);
+                    break;
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    let status = process.wait();
+                    return !status.is_ok_and(|status| status.success()) || timed_out;
+                }
+            }
+        }
+    }
+}
+
+struct DayOutput {
+    day: Day,
+    parts: Vec<PartResult>,
+    failed: bool,
+}
+
+/// Collects the results of a day, to be printed once every day has been solved.
+struct Collector {
+    results: Vec<PartResult>,
+}
+
+impl Reporter for Collector {
+    fn part_finished(&mut self, result: &PartResult) {
+        self.results.push(result.clone());
+    }
+}
+
+/// Runs a solution, catching its panics so that the other days still get solved.
+fn run_day(solution: &Solution, options: &Options) -> DayOutput {
+    let (year, day) = (solution.year, solution.day);
+    let mut collector = Collector {
+        results: Vec::new(),
+    };
+
+    let child = |skip: &[u8]| child_command(year, day, options, skip);
+    let failed = match options.source.read(year, day) {
+        Ok(input) => run_solution(solution, &input, options, &mut collector, &child),
+        Err(e) => {
+            eprintln!("🫎 Could not open input file: {}", e);
+            true
+        }
+    };
+
+    let mut result = DayOutput {
+        day,
+        parts: collector.results,
+        failed,
+    };
+    if result.failed {
+        for part in 1..=2 {
+            if !result.parts.iter().any(|p| p.part == part) {
+                result
+                    .parts
+                    .push(PartResult::failed(year, day, part, "failed"));
+            }
+        }
+    }
+
+    result
+}
+
+fn print_summary(year: u16, results: &[DayOutput], regression_threshold: f64) {
+    // The parsing step only gets a column when a solution declares one.
+    let parse = results
+        .iter()
+        .flat_map(|day| day.parts.iter())
+        .any(|part| part.part == PARSE_PART);
+    let mut headers = vec!["Part 1", "Time", "Part 2", "Time"];
+    if parse {
+        headers.insert(0, "Parse");
+    }
+    let offset = usize::from(parse);
+
+    let time = |part: &PartResult| match (part.mean, part.last) {
+        (Some(mean), Some(last)) if delta(mean, last) > regression_threshold => {
+            format!("{:.1?} (⚠ {:+.1}%)", mean, delta(mean, last))
+        }
+        (Some(mean), _) => format!("{:.1?}", mean),
+        (None, _) => String::new(),
+    };
+
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|day| {
+            let mut row = vec![String::new(); headers.len()];
+            row[offset] = "-".to_string();
+            row[offset + 2] = "-".to_string();
+            for part in &day.parts {
+                if part.part == PARSE_PART {
+                    row[0] = time(part);
+                    continue;
+                }
+                let column = offset + 2 * (part.part as usize - 1);
+                row[column] = match &part.error {
+                    Some(error) => format!("✗ {}", error),
+                    None => part.checked_answer(),
+                };
+                row[column + 1] = time(part);
+            }
+            row
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .chain([headers[column].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let format_row = |first: &str, row: &[&str]| {
+        let mut line = first.to_string();
+        for (column, (value, width)) in row.iter().zip(&widths).enumerate() {
+            // Answers are left-aligned, times right-aligned.
+            if column >= offset && (column - offset) % 2 == 0 {
+                line += &format!(" | {:<width$}", value, width = width);
+            } else {
+                line += &format!(" | {:>width$}", value, width = width);
+            }
+        }
+        line.trim_end().to_string()
+    };
+
+    println!();
+    println!("{}", format_row("Day", &headers));
+    println!(
+        "---{}",
+        widths
+            .iter()
+            .map(|width| format!("-+-{}", "-".repeat(*width)))
+            .collect::<String>()
+    );
+    for (day, row) in results.iter().zip(&rows) {
+        let row: Vec<&str> = row.iter().map(String::as_str).collect();
+        println!("{}", format_row(&format!(" {}", day.day), &row));
     }
 
-    let mut command = Command::new("cargo")
-        .args(&args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+    let total: Duration = results
+        .iter()
+        .flat_map(|day| day.parts.iter())
+        .filter_map(|part| part.mean)
+        .sum();
+    let failed = results.iter().filter(|day| day.failed).count();
+    let wrong = results
+        .iter()
+        .flat_map(|day| day.parts.iter())
+        .filter(|part| part.correct() == Some(false))
+        .count();
+    println!();
+    println!("🎄 Total for {}: {:.1?}", year, total);
+    if failed > 0 {
+        println!("🫎 {} day(s) failed", failed);
+    }
+    if wrong > 0 {
+        println!("🫎 {} wrong answer(s)", wrong);
+    }
+}
+
+/// Solves every day of the year that has a solution (optionally restricted to a range of days),
+/// then prints a summary table of the answers and timings.
+///
+/// With another report format than [`ReportFormat::Pretty`], the results of every day are given
+/// to a single reporter instead of the summary table.
+///
+/// Exits with an error if a day failed or an answer does not match the answer store.
+pub fn handle_all(event: Event, days: Option<DayRange>, options: &Options, solutions: &[Solution]) {
+    let year = event.year();
+    let solutions: Vec<&Solution> = solutions
+        .iter()
+        .filter(|solution| solution.year == year && event.contains(solution.day))
+        .filter(|solution| days.is_none_or(|range| range.contains(solution.day)))
+        .collect();
+
+    if solutions.is_empty() {
+        eprintln!("🫎 No solution found for {}", year);
+        process::exit(1);
+    }
+    if matches!(options.source, InputSource::File(_) | InputSource::Stdin) {
+        eprintln!("🫎 An input file can only be given when solving a single day");
+        process::exit(1);
+    }
+
+    options.warn_debug_build();
+    let mut results = Vec::new();
+    for solution in solutions {
+        eprintln!("🎄 Solving day {}...", solution.day);
+        results.push(run_day(solution, options));
+    }
+
+    if options.report == ReportFormat::Pretty {
+        print_summary(year, &results, options.regression_threshold);
+    } else {
+        let mut reporter = options.report.reporter(options.regression_threshold);
+        for part in results.iter().flat_map(|day| day.parts.iter()) {
+            reporter.part_finished(part);
+        }
+        reporter.finish();
+    }
+
+    // Failed days and wrong answers make the run fail, to catch regressions.
+    let wrong = results
+        .iter()
+        .flat_map(|day| day.parts.iter())
+        .any(|part| part.correct() == Some(false));
+    if wrong || results.iter().any(|day| day.failed) {
+        process::exit(1);
+    }
+}
 
-    command.wait().unwrap();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{history::DEFAULT_REGRESSION_THRESHOLD, run_parse, run_part};
+    use crate::day;
+
+    /// Name of the solution run by [`child`], when the tests run it in a child process.
+    const CHILD_ENV_VAR: &str = "AOC_TEST_CHILD";
+    /// Parts skipped by [`child`], separated by commas.
+    const SKIP_ENV_VAR: &str = "AOC_TEST_SKIP";
+
+    fn options(timeout: Duration) -> Options {
+        Options {
+            release: false,
+            report: ReportFormat::Pretty,
+            regression_threshold: DEFAULT_REGRESSION_THRESHOLD,
+            bench: BenchConfig::default(),
+            // Answers are only saved for the puzzle input.
+            source: InputSource::File("input.txt".into()),
+            profile: false,
+            timeout: Some(timeout),
+        }
+    }
+
+    fn never() -> Option<u32> {
+        loop {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn slow_part(input: &str, reporter: &mut dyn Reporter, options: &RunOptions) {
+        let parsed = run_parse(str::len, input, 2015, day!(1), reporter, options);
+        run_part(
+            |_: &usize| never(),
+            &parsed,
+            2015,
+            day!(1),
+            1,
+            reporter,
+            options,
+        );
+        run_part(
+            |len: &usize| Some(*len),
+            &parsed,
+            2015,
+            day!(1),
+            2,
+            reporter,
+            options,
+        );
+    }
+
+    fn slow_parse(input: &str, reporter: &mut dyn Reporter, options: &RunOptions) {
+        let parsed = run_parse(|_: &str| never(), input, 2015, day!(2), reporter, options);
+        run_part(
+            |_: &Option<u32>| Some(1),
+            &parsed,
+            2015,
+            day!(2),
+            1,
+            reporter,
+            options,
+        );
+    }
+
+    fn quick(input: &str, reporter: &mut dyn Reporter, options: &RunOptions) {
+        run_part(
+            |input: &str| Some(input.len()),
+            input,
+            2015,
+            day!(3),
+            1,
+            reporter,
+            options,
+        );
+    }
+
+    fn solution(name: &str) -> Solution {
+        let solution = |day, run| Solution {
+            year: 2015,
+            day,
+            run,
+        };
+        match name {
+            "slow_part" => solution(day!(1), slow_part),
+            "slow_parse" => solution(day!(2), slow_parse),
+            _ => solution(day!(3), quick),
+        }
+    }
+
+    /// Runs a solution of the tests as the child process of [`run`], which runs this test binary
+    /// again for this test only. Does nothing otherwise.
+    #[test]
+    fn child() {
+        let Ok(name) = env::var(CHILD_ENV_VAR) else {
+            return;
+        };
+        let skip = env::var(SKIP_ENV_VAR)
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|part| part.parse().ok())
+            .collect();
+        let options = RunOptions {
+            skip,
+            ..options(Duration::MAX).run_options()
+        };
+        run_events(&solution(&name), &options);
+    }
+
+    fn run(name: &str, timeout: Duration) -> (bool, Vec<PartResult>) {
+        let child = |skip: &[u8]| {
+            let skip: Vec<String> = skip.iter().map(u8::to_string).collect();
+            let mut command = Command::new(env::current_exe().unwrap());
+            command
+                .args([
+                    "commands::solve::tests::child",
+                    "--exact",
+                    "--nocapture",
+                    "--quiet",
+                ])
+                .env(CHILD_ENV_VAR, name)
+                .env(SKIP_ENV_VAR, skip.join(","));
+            command
+        };
+        let mut collector = Collector {
+            results: Vec::new(),
+        };
+        let failed = run_solution(
+            &solution(name),
+            "12345",
+            &options(timeout),
+            &mut collector,
+            &child,
+        );
+        (failed, collector.results)
+    }
+
+    #[test]
+    fn test_run_solution_timeout() {
+        let (failed, results) = run("slow_part", Duration::from_millis(500));
+        assert!(failed);
+        // The input is parsed again for the second part, but only reported once.
+        let parts: Vec<u8> = results.iter().map(|result| result.part).collect();
+        assert_eq!(parts, [PARSE_PART, 1, 2]);
+        assert!(results[1]
+            .error
+            .as_ref()
+            .is_some_and(|error| error.starts_with("timed out after")));
+        assert_eq!(results[2].answer.as_deref(), Some("5"));
+        assert_eq!(results[2].error, None);
+    }
+
+    #[test]
+    fn test_run_solution_parse_timeout() {
+        let (failed, results) = run("slow_parse", Duration::from_millis(500));
+        assert!(failed);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].part, PARSE_PART);
+        assert!(results[0].error.is_some());
+    }
+
+    #[test]
+    fn test_run_solution_in_time() {
+        // A timeout too long to be a deadline is no timeout.
+        let (failed, results) = run("quick", Duration::MAX);
+        assert!(!failed);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].answer.as_deref(), Some("5"));
+    }
+
+    #[test]
+    fn test_options_args() {
+        let mut options = Options {
+            release: true,
+            report: ReportFormat::Csv,
+            source: InputSource::Stdin,
+            ..options(Duration::from_millis(1500))
+        };
+        let day = Day::new(3).unwrap();
+
+        assert_eq!(
+            options.args(2024, day).join(" "),
+            "2024 solve 03 --input - --report csv --regression-threshold 10 --warmup 0.5 \
+             --bench-time 5 --bench-iterations 10000 --timeout 1.5s --release"
+        );
+
+        options.release = false;
+        options.timeout = None;
+        options.source = InputSource::Example(2);
+        options.bench.cpu = Some(1);
+        assert_eq!(
+            options.args(2024, day).join(" "),
+            "2024 solve 03 --example 2 --report csv --regression-threshold 10 --warmup 0.5 \
+             --bench-time 5 --bench-iterations 10000 --pin 1"
+        );
+    }
 }
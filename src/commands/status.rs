@@ -0,0 +1,231 @@
+use std::{
+    fs::{metadata, read_dir, read_to_string},
+    path::Path,
+};
+
+use super::{answers::AnswerStore, data_path, Day, Event};
+
+/// State of a solution part, as read from the source of its module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PartState {
+    /// The part function is missing.
+    Missing,
+    /// The part still returns `None`, as in the templates.
+    Todo,
+    /// The part is implemented, but its answer is not verified yet.
+    Implemented,
+    /// The answer of the part is in the answer store, or asserted by its input test.
+    Solved,
+}
+
+impl PartState {
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Missing => "-",
+            Self::Todo => "todo",
+            Self::Implemented => "wip",
+            Self::Solved => "★",
+        }
+    }
+}
+
+struct DayStatus {
+    day: Day,
+    module: bool,
+    input: bool,
+    examples: usize,
+    parts: [PartState; 2],
+}
+
+/// Returns the body of the first function with the given name, without its braces.
+pub(crate) fn function_body<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let start = source.find(&format!("fn {}(", name))?;
+    let open = start + source[start..].find('{')?;
+
+    let mut depth = 0;
+    for (index, c) in source[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&source[open + 1..open + index]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Marks the `None` of a template stub that does more than returning it, e.g. parsing the input.
+const STUB_MARKER: &str = "// TODO: solve the part";
+
+/// Returns whether a part body is a stub: only `None`, or ending with `None` after the marker.
+fn is_stub(body: &str) -> bool {
+    let body = body.trim();
+    body == "None" || (body.contains(STUB_MARKER) && body.ends_with("None"))
+}
+
+fn part_state(source: &str, name: &str, recorded: bool) -> PartState {
+    match function_body(source, &format!("part_{}", name)) {
+        None => PartState::Missing,
+        Some(body) if is_stub(body) => PartState::Todo,
+        Some(_) if recorded => PartState::Solved,
+        Some(_) => match function_body(source, &format!("input_part_{}", name)) {
+            Some(test) if test.contains("Some(") => PartState::Solved,
+            _ => PartState::Implemented,
+        },
+    }
+}
+
+fn day_status(year: u16, day: Day, answers: Option<&AnswerStore>) -> DayStatus {
+    let source = read_to_string(format!("src/bin/{}-{}/main.rs", year, day)).ok();
+    let input = metadata(data_path("inputs", year, &format!("{}.txt", day)))
+        .is_ok_and(|metadata| metadata.len() > 0);
+    let examples = read_dir(data_path("examples", year, ""))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| {
+                    entry
+                        .file_name()
+                        .to_string_lossy()
+                        .starts_with(&format!("{}-", day))
+                })
+                .count()
+        })
+        .unwrap_or_default();
+
+    let parts = match &source {
+        Some(source) => [("one", 1), ("two", 2)].map(|(name, part)| {
+            let recorded = answers.is_some_and(|answers| answers.get(day, part).is_some());
+            part_state(source, name, recorded)
+        }),
+        None => [PartState::Missing; 2],
+    };
+
+    DayStatus {
+        day,
+        module: source.is_some(),
+        input,
+        examples,
+        parts,
+    }
+}
+
+/// Prints a matrix of the state of every day of an event, from the files in the tree.
+pub fn handle(event: Event) {
+    let year = event.year();
+    let check = |value: bool| if value { "✓" } else { "-" };
+    let answers = AnswerStore::load(year).ok();
+    let days: Vec<DayStatus> = event
+        .days()
+        .map(|day| day_status(year, day, answers.as_ref()))
+        .collect();
+
+    println!("🎄 Advent of Code {}", year);
+    println!();
+    println!("Day | Module | Input | Examples | Part 1 | Part 2");
+    println!("----+--------+-------+----------+--------+-------");
+    for status in &days {
+        let examples = match status.examples {
+            0 => "-".to_string(),
+            count => count.to_string(),
+        };
+        let line = format!(
+            " {} | {:^6} | {:^5} | {:^8} | {:^6} | {:^6}",
+            status.day,
+            check(status.module),
+            check(status.input),
+            examples,
+            status.parts[0].symbol(),
+            status.parts[1].symbol()
+        );
+        println!("{}", line.trim_end());
+    }
+
+    let scaffolded = days.iter().filter(|status| status.module).count();
+    let stars = days
+        .iter()
+        .flat_map(|status| status.parts)
+        .filter(|part| *part == PartState::Solved)
+        .count();
+    println!();
+    println!(
+        "🎄 {} day(s) scaffolded, {} verified answer(s)",
+        scaffolded, stars
+    );
+    if !Path::new("src/bin").exists() {
+        eprintln!("🫎 No `src/bin` directory, run `status` from the root of the repo");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = parse(input);
+    if grid.is_empty() {
+        return None;
+    }
+    Some(grid.len())
+}
+
+pub fn part_two(_input: &str) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn input_part_one() {
+        let result = part_one(&read_input(YEAR, DAY));
+        assert_eq!(result, Some(42));
+    }
+
+    #[test]
+    fn input_part_two() {
+        let result = part_two(&read_input(YEAR, DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    #[test]
+    fn test_part_state() {
+        let implemented = "fn part_one(_: &str) -> Option<u8> { Some(1) }";
+        assert_eq!(part_state(SOURCE, "one", false), PartState::Solved);
+        assert_eq!(part_state(SOURCE, "two", true), PartState::Todo);
+        assert_eq!(part_state("", "one", false), PartState::Missing);
+        assert_eq!(
+            part_state(implemented, "one", false),
+            PartState::Implemented
+        );
+        assert_eq!(part_state(implemented, "one", true), PartState::Solved);
+    }
+
+    #[test]
+    fn test_template_part_state() {
+        let templates = [
+            include_str!("../../templates/default/main.rs"),
+            include_str!("../../templates/grid/main.rs"),
+            include_str!("../../templates/lines/main.rs"),
+            include_str!("../../templates/modules/main.rs"),
+        ];
+        for template in templates {
+            assert_eq!(part_state(template, "one", false), PartState::Todo);
+            assert_eq!(part_state(template, "two", false), PartState::Todo);
+
+            // Replacing the stub `None` implements the part, even if the marker is left.
+            let implemented = template.replacen("    None\n}", "    Some(1)\n}", 1);
+            assert_eq!(
+                part_state(&implemented, "one", false),
+                PartState::Implemented
+            );
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::process;
+
+use chrono::Utc;
+
+use super::answers::AnswerStore;
+use super::aoc::{submit_answer, Verdict};
+use super::ledger::{Attempt, Ledger};
+use super::{read_last_answer, Day};
+
+pub fn handle(year: u16, day: Day, part: u8, answer: Option<String>) {
+    let answer = match answer.or_else(|| read_last_answer(year, day, part)) {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            eprintln!("🫎 No answer given and no previous run found, run `solve` first");
+            process::exit(1);
+        }
+    };
+
+    let mut ledger = Ledger::load(year, day).unwrap_or_else(|e| {
+        eprintln!("🫎 Failed to read submission ledger: {}", e);
+        process::exit(1);
+    });
+
+    if let Err(refusal) = ledger.check(part, &answer, Utc::now()) {
+        eprintln!("🫎 Refusing to submit {}: {}", answer, refusal);
+        process::exit(1);
+    }
+
+    println!("🎄 Submitting Day {} Part {}: {}", day, part, answer);
+    let verdict = match submit_answer(year, day, part, &answer) {
+        Ok(verdict) => verdict,
+        Err(e) => {
+            eprintln!("🫎 Failed to submit answer: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let attempt = Attempt {
+        time: Utc::now(),
+        part,
+        answer: answer.clone(),
+        verdict,
+    };
+    if let Err(e) = ledger.record(attempt) {
+        eprintln!("🫎 Failed to record submission: {}", e);
+    }
+
+    if verdict == Verdict::Correct {
+        let recorded = AnswerStore::load(year).and_then(|mut store| {
+            store.insert(day, part, answer);
+            store.save()
+        });
+        if let Err(e) = recorded {
+            eprintln!("🫎 Failed to record answer: {}", e);
+        }
+    }
+
+    match verdict {
+        Verdict::Correct => println!("⭐ That's the right answer!"),
+        Verdict::TooHigh => println!("🫎 That's not the right answer, it is too high"),
+        Verdict::TooLow => println!("🫎 That's not the right answer, it is too low"),
+        Verdict::Incorrect => println!("🫎 That's not the right answer"),
+        Verdict::Wait(seconds) => println!("🫎 Answer submitted too recently, wait {}s", seconds),
+        Verdict::WrongLevel => println!("🫎 Wrong level, is this part already solved?"),
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    env,
+    fs::metadata,
+    path::Path,
+    process::{self, Command, Output},
+};
+
+use regex::Regex;
+
+use super::{data_path, Day, Event};
+
+/// Which of the tests generated from the template are run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Filter {
+    #[default]
+    All,
+    ExamplesOnly,
+    InputsOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+/// The results of the tests of a day, by part: `(example, input)`.
+#[derive(Debug, Default)]
+struct DayResults {
+    parts: [(Option<Outcome>, Option<Outcome>); 2],
+    /// Output of the failed tests, or of the build.
+    failures: Vec<String>,
+}
+
+/// Parses the output of the test harness, and the sections of the failed tests.
+fn parse_output(stdout: &str) -> DayResults {
+    let test =
+        Regex::new(r"(?m)^test (?:\S+::)?(example|input)_part_(one|two) \.\.\. (\w+)").unwrap();
+    let mut results = DayResults::default();
+
+    for c in test.captures_iter(stdout) {
+        let outcome = match &c[3] {
+            "ok" => Outcome::Passed,
+            "ignored" => Outcome::Ignored,
+            _ => Outcome::Failed,
+        };
+        let part = &mut results.parts[usize::from(&c[2] == "two")];
+        match &c[1] {
+            "example" => part.0 = Some(outcome),
+            _ => part.1 = Some(outcome),
+        }
+    }
+
+    results.failures = stdout
+        .split("\n---- ")
+        .skip(1)
+        .filter_map(|section| {
+            let (header, output) = section.split_once('\n')?;
+            let output = output.trim_start_matches('\n').split("\n\n").next()?;
+            Some(format!("---- {}\n{}", header, output.trim_end()))
+        })
+        .collect();
+    results
+}
+
+fn cargo_test(year: u16, day: Day) -> Command {
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or("cargo".to_string()));
+    cargo
+        .args(["test", "--bin", &format!("{}-{}", year, day)])
+        .env("RUST_BACKTRACE", "0");
+    cargo
+}
+
+fn output(mut command: Command) -> Output {
+    command.output().unwrap_or_else(|e| {
+        eprintln!("🫎 Failed to run cargo: {}", e);
+        process::exit(1);
+    })
+}
+
+/// Runs the given tests of a day, one at a time since a missing file makes the test harness exit.
+fn run_tests(year: u16, day: Day, tests: &[&str]) -> DayResults {
+    let mut results = DayResults::default();
+    let build = output({
+        let mut command = cargo_test(year, day);
+        command.arg("--no-run");
+        command
+    });
+    if !build.status.success() {
+        results.failures.push(
+            String::from_utf8_lossy(&build.stderr)
+                .trim_end()
+                .to_string(),
+        );
+        return results;
+    }
+
+    for test in tests {
+        let output = output({
+            let mut command = cargo_test(year, day);
+            command.args(["--", "--exact", &format!("tests::{}", test)]);
+            command
+        });
+        let result = parse_output(&String::from_utf8_lossy(&output.stdout));
+        let outcome = result
+            .parts
+            .iter()
+            .flat_map(|(example, input)| [example, input])
+            .flatten()
+            .next()
+            .copied();
+        let (part, input) = (
+            usize::from(test.ends_with("two")),
+            test.starts_with("input"),
+        );
+        let outcome = match outcome {
+            // The harness exited before reporting, e.g. on a missing example file.
+            None if !output.status.success() => {
+                results.failures.push(format!(
+                    "---- tests::{} exited abnormally, is its example or input file missing? ----",
+                    test
+                ));
+                Some(Outcome::Failed)
+            }
+            outcome => outcome,
+        };
+        if input {
+            results.parts[part].1 = outcome;
+        } else {
+            results.parts[part].0 = outcome;
+        }
+        results.failures.extend(result.failures);
+    }
+    results
+}
+
+fn symbol(outcome: Option<Outcome>) -> &'static str {
+    match outcome {
+        Some(Outcome::Passed) => "✓",
+        Some(Outcome::Failed) => "✗",
+        Some(Outcome::Ignored) => "ignored",
+        None => "-",
+    }
+}
+
+/// Runs the example and input tests of a day, or of every scaffolded day of the event, then prints
+/// a summary by day and part.
+///
+/// The input tests of days without input file are not run, and reported separately.
+pub fn handle(event: Event, day: Option<Day>, filter: Filter) {
+    let year = event.year();
+    let scaffolded = |day: &Day| Path::new(&format!("src/bin/{}-{}", year, day)).exists();
+    let days: Vec<Day> = match day {
+        Some(day) if !scaffolded(&day) => {
+            eprintln!("🫎 Day {} is not scaffolded", day);
+            process::exit(1);
+        }
+        Some(day) => vec![day],
+        None => event.days().filter(scaffolded).collect(),
+    };
+
+    let mut results = Vec::new();
+    let mut missing_inputs = Vec::new();
+    for day in days {
+        let input = metadata(data_path("inputs", year, &format!("{}.txt", day)))
+            .is_ok_and(|metadata| metadata.len() > 0);
+        if !input && filter != Filter::ExamplesOnly {
+            missing_inputs.push(day);
+        }
+
+        let examples = ["example_part_one", "example_part_two"];
+        let inputs = ["input_part_one", "input_part_two"];
+        let tests: Vec<&str> = match filter {
+            Filter::All if input => examples.into_iter().chain(inputs).collect(),
+            Filter::InputsOnly if input => inputs.to_vec(),
+            Filter::InputsOnly => continue,
+            _ => examples.to_vec(),
+        };
+        eprintln!("🎄 Testing day {}...", day);
+        results.push((day, run_tests(year, day, &tests)));
+    }
+
+    println!();
+    println!("Day | Example 1 | Input 1 | Example 2 | Input 2");
+    println!("----+-----------+---------+-----------+--------");
+    for (day, result) in &results {
+        let [(example_1, input_1), (example_2, input_2)] = result.parts;
+        let line = format!(
+            " {} | {:^9} | {:^7} | {:^9} | {:^7}",
+            day,
+            symbol(example_1),
+            symbol(input_1),
+            symbol(example_2),
+            symbol(input_2)
+        );
+        println!("{}", line.trim_end());
+    }
+
+    let outcomes = || {
+        results
+            .iter()
+            .flat_map(|(_, result)| result.parts)
+            .flat_map(|(example, input)| [example, input])
+            .flatten()
+    };
+    let passed = outcomes().filter(|o| *o == Outcome::Passed).count();
+    let failed = outcomes().filter(|o| *o == Outcome::Failed).count();
+    let broken: Vec<&(Day, DayResults)> = results
+        .iter()
+        .filter(|(_, result)| !result.failures.is_empty())
+        .collect();
+
+    for (day, result) in &broken {
+        println!();
+        println!("🫎 Day {}:", day);
+        for failure in &result.failures {
+            println!("{}", failure);
+        }
+    }
+
+    println!();
+    println!("🎄 {} test(s) passed, {} failed", passed, failed);
+    if !missing_inputs.is_empty() {
+        let days: Vec<String> = missing_inputs.iter().map(Day::to_string).collect();
+        println!(
+            "🫎 No input file for day(s) {}, their input tests were not run",
+            days.join(", ")
+        );
+    }
+    if failed > 0 || !broken.is_empty() {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_output() {
+        let stdout = "
+running 3 tests
+test tests::example_part_one ... ok
+test tests::example_part_two ... FAILED
+test tests::input_part_one ... ignored
+
+failures:
+
+---- tests::example_part_two stdout ----
+assertion `left == right` failed
+  left: Some(3)
+ right: Some(4)
+
+
+failures:
+    tests::example_part_two
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 2 filtered out
+";
+        let results = parse_output(stdout);
+
+        assert_eq!(
+            results.parts,
+            [
+                (Some(Outcome::Passed), Some(Outcome::Ignored)),
+                (Some(Outcome::Failed), None)
+            ]
+        );
+        assert_eq!(
+            results.failures,
+            ["---- tests::example_part_two stdout ----\nassertion `left == right` failed\n  left: Some(3)\n right: Some(4)"]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    collections::BTreeMap,
+    fs::{create_dir_all, read_to_string, write},
+    io,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use super::{file::data_dir, Day};
+
+/// Verified answers of a year, shared by the runner, the tests and the `record` command.
+///
+/// The answers are stored in `data/answers/<year>.txt`, one `day\tpart\tanswer` line per part.
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: BTreeMap<(Day, u8), String>,
+}
+
+impl AnswerStore {
+    pub fn load(year: u16) -> io::Result<Self> {
+        let path = data_dir("answers").join(format!("{}.txt", year));
+        let answers = match read_to_string(&path) {
+            Ok(content) => content.lines().filter_map(parse_line).collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self { path, answers })
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Sets the answer of a part, returning the previous one.
+    pub fn insert(&mut self, day: Day, part: u8, answer: String) -> Option<String> {
+        self.answers.insert((day, part), answer)
+    }
+
+    /// Writes every answer back to the store file, sorted by day and part.
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            create_dir_all(parent)?;
+        }
+        let content: String = self
+            .answers
+            .iter()
+            .map(|((day, part), answer)| format!("{}\t{}\t{}\n", day, part, answer))
+            .collect();
+        write(&self.path, content)
+    }
+}
+
+fn parse_line(line: &str) -> Option<((Day, u8), String)> {
+    let mut fields = line.splitn(3, '\t');
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    Some(((day, part), fields.next()?.to_string()))
+}
+
+/// Reads the verified answer of a part from the answer store, or [`None`] if it is unknown.
+///
+/// The answer is parsed into the return type of the part, so that tests can compare it directly:
+/// `assert_eq!(part_one(&input), read_answer(YEAR, DAY, 1))`.
+pub fn read_answer<T: FromStr>(year: u16, day: Day, part: u8) -> Option<T> {
+    AnswerStore::load(year)
+        .ok()?
+        .get(day, part)
+        .and_then(|answer| answer.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("07\t2\t204976636995111"),
+            Some(((Day::new(7).unwrap(), 2), "204976636995111".to_string()))
+        );
+        assert_eq!(parse_line("7\t2"), None);
+        assert_eq!(parse_line("# comment"), None);
+    }
+}
@@ -1,24 +1,207 @@
-use std::path::Path;
+use std::{
+    env,
+    fmt::Display,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
 
-use aoc_client::{AocClient, AocResult};
+use aoc_client::{AocError, AocResult};
+use chrono::{DateTime, TimeZone, Utc};
+use regex::Regex;
 
-use super::Day;
+use super::{transport::Transport, Day};
 
-fn get_client(year: u16, day: Day) -> AocResult<AocClient> {
-    const AOC_COOKIE_FILE: &str = ".adventofcode.session";
+const AOC_COOKIE_FILE: &str = ".adventofcode.session";
+const AOC_COOKIE_ENV_VAR: &str = "ADVENT_OF_CODE_SESSION";
 
-    let mut client = AocClient::builder();
+/// Inputs never change, so they are only downloaded again when forced.
+const INPUT_MAX_AGE: Duration = Duration::MAX;
+/// Puzzle pages change when the first part is solved, the cached page is dropped by a correct
+/// submission.
+const PUZZLE_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+/// The JSON of private leaderboards should not be requested more than once every 15 minutes.
+const LEADERBOARD_MAX_AGE: Duration = Duration::from_secs(15 * 60);
 
-    if Path::new(&AOC_COOKIE_FILE).exists() {
-        client.session_cookie_from_file(AOC_COOKIE_FILE)?;
+/// Reads the session cookie from the `.adventofcode.session` file at the root of the repo, the
+/// `ADVENT_OF_CODE_SESSION` environment variable, or the home and config directories.
+fn get_session_cookie() -> AocResult<String> {
+    let path = if Path::new(&AOC_COOKIE_FILE).exists() {
+        PathBuf::from(AOC_COOKIE_FILE)
+    } else if let Ok(cookie) = env::var(AOC_COOKIE_ENV_VAR) {
+        return Ok(cookie.trim().to_string());
     } else {
-        client.session_cookie_from_default_locations()?;
+        let home = env::var_os("HOME").map(PathBuf::from);
+        let config = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|home| home.join(".config")));
+        home.map(|home| home.join(AOC_COOKIE_FILE))
+            .into_iter()
+            .chain(config.map(|config| config.join("adventofcode.session")))
+            .find(|path| path.exists())
+            .ok_or(AocError::SessionFileNotFound)?
     };
 
-    client.year(year.into())?.day(day.into())?.build()
+    read_to_string(&path)
+        .map(|cookie| cookie.trim().to_string())
+        .map_err(|e| AocError::SessionFileReadError {
+            filename: path.display().to_string(),
+            source: e,
+        })
 }
 
-pub fn get_input(year: u16, day: Day) -> AocResult<String> {
-    let client = get_client(year, day)?;
-    client.get_input()
+/// Returns the instant the puzzle of a day is released, at midnight EST (05:00 UTC).
+pub fn unlock_time(year: u16, day: Day) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year.into(), 12, day.into(), 5, 0, 0)
+        .unwrap()
+}
+
+/// Returns whether the puzzle of a day is released.
+pub fn day_unlocked(year: u16, day: Day) -> bool {
+    Utc::now() >= unlock_time(year, day)
+}
+
+/// Returns the transport to fetch the pages of an unlocked day.
+fn get_transport(year: u16, day: Day) -> AocResult<Transport> {
+    if !day_unlocked(year, day) {
+        return Err(AocError::LockedPuzzle(day.into(), year.into()));
+    }
+    Transport::new(&get_session_cookie()?)
+}
+
+fn puzzle_url(year: u16, day: Day) -> String {
+    format!("/{}/day/{}", year, u8::from(day))
+}
+
+/// Downloads the input of a day, bypassing the response cache with `refresh`.
+pub fn get_input(year: u16, day: Day, refresh: bool) -> AocResult<String> {
+    let transport = get_transport(year, day)?;
+    let path = format!("{}/input", puzzle_url(year, day));
+    transport.get(&path, INPUT_MAX_AGE, refresh)
+}
+
+/// Downloads the puzzle page of a day (its `<main>` element), bypassing the response cache with
+/// `refresh`.
+pub fn get_puzzle_html(year: u16, day: Day, refresh: bool) -> AocResult<String> {
+    let transport = get_transport(year, day)?;
+    let page = transport.get(&puzzle_url(year, day), PUZZLE_MAX_AGE, refresh)?;
+    Regex::new(r"(?is)<main>(.*)</main>")
+        .unwrap()
+        .captures(&page)
+        .map(|c| c[1].to_string())
+        .ok_or(AocError::AocResponseError)
+}
+
+/// Downloads the JSON of a private leaderboard.
+pub fn get_leaderboard_json(year: u16, id: u64) -> AocResult<String> {
+    let transport = Transport::new(&get_session_cookie()?)?;
+    transport
+        .get(
+            &format!("/{}/leaderboard/private/view/{}.json", year, id),
+            LEADERBOARD_MAX_AGE,
+            false,
+        )
+        .map_err(|e| match e {
+            AocError::AocResponseError => AocError::PrivateLeaderboardNotAvailable,
+            e => e,
+        })
+}
+
+/// Submits an answer and returns the verdict of adventofcode.com.
+pub fn submit_answer(year: u16, day: Day, part: u8, answer: &str) -> AocResult<Verdict> {
+    let transport = get_transport(year, day)?;
+    let response = transport.post(
+        &format!("{}/answer", puzzle_url(year, day)),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+
+    let verdict = Verdict::from_response(&response).ok_or(AocError::AocResponseError)?;
+    if verdict == Verdict::Correct {
+        // The puzzle page now has the next part (or the answer).
+        transport.invalidate(&puzzle_url(year, day));
+    }
+    Ok(verdict)
+}
+
+/// The outcome of an answer submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// An answer was submitted too recently, the number of seconds left to wait is given.
+    Wait(u64),
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Parses the verdict from the HTML response of a submission.
+    pub fn from_response(html: &str) -> Option<Self> {
+        if html.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Some(Self::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Some(Self::TooLow)
+            } else {
+                Some(Self::Incorrect)
+            }
+        } else if html.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")
+                .unwrap()
+                .captures(html)
+                .map(|c| {
+                    let minutes = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                    let seconds: u64 = c[2].parse().unwrap();
+                    minutes * 60 + seconds
+                })
+                .unwrap_or(60);
+            Some(Self::Wait(wait))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the verdict proves that the answer is wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Incorrect)
+    }
+}
+
+/// Renders the verdict as stored in the submission ledger.
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => f.write_str("correct"),
+            Self::TooHigh => f.write_str("too-high"),
+            Self::TooLow => f.write_str("too-low"),
+            Self::Incorrect => f.write_str("incorrect"),
+            Self::Wait(seconds) => write!(f, "wait-{}", seconds),
+            Self::WrongLevel => f.write_str("wrong-level"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "incorrect" => Ok(Self::Incorrect),
+            "wrong-level" => Ok(Self::WrongLevel),
+            _ => s
+                .strip_prefix("wait-")
+                .and_then(|seconds| seconds.parse().ok())
+                .map(Self::Wait)
+                .ok_or(()),
+        }
+    }
 }
@@ -0,0 +1,235 @@
+use std::{env, io, str::FromStr, time::Duration};
+
+pub const BENCH_TIME_ENV_VAR: &str = "AOC_BENCH_TIME";
+pub const BENCH_ITERATIONS_ENV_VAR: &str = "AOC_BENCH_ITERATIONS";
+pub const BENCH_WARMUP_ENV_VAR: &str = "AOC_BENCH_WARMUP";
+pub const BENCH_CPU_ENV_VAR: &str = "AOC_BENCH_CPU";
+
+/// Number of CPUs a benchmark can be pinned to.
+#[cfg(target_os = "linux")]
+pub const CPU_COUNT: usize = libc::CPU_SETSIZE as usize;
+/// Number of CPUs a benchmark can be pinned to.
+#[cfg(not(target_os = "linux"))]
+pub const CPU_COUNT: usize = 0;
+
+/// Configuration of the release benchmarks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchConfig {
+    /// Time spent running the solution before measuring it.
+    pub warmup: Duration,
+    /// Time budget of the measurements.
+    pub time_budget: Duration,
+    /// Maximum number of measured iterations.
+    pub max_iterations: usize,
+    /// CPU the benchmark is pinned to (Linux only).
+    pub cpu: Option<usize>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(500),
+            time_budget: Duration::from_secs(5),
+            max_iterations: 10_000,
+            cpu: None,
+        }
+    }
+}
+
+fn env_var<T: FromStr>(name: &str) -> Option<T> {
+    env::var(name).ok().and_then(|value| value.parse().ok())
+}
+
+/// Reads a duration in seconds, ignoring negative and non-finite values.
+fn env_seconds(name: &str) -> Option<Duration> {
+    env_var(name).and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+}
+
+impl BenchConfig {
+    /// Reads the configuration from the `AOC_BENCH_*` environment variables.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            warmup: env_seconds(BENCH_WARMUP_ENV_VAR).unwrap_or(default.warmup),
+            time_budget: env_seconds(BENCH_TIME_ENV_VAR).unwrap_or(default.time_budget),
+            max_iterations: env_var(BENCH_ITERATIONS_ENV_VAR).unwrap_or(default.max_iterations),
+            cpu: env_var(BENCH_CPU_ENV_VAR),
+        }
+    }
+}
+
+/// Statistics of the samples of a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    /// Number of samples outside of the Tukey fences (1.5 times the interquartile range).
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Computes the statistics of a set of samples, or returns [`None`] if there is no sample.
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let nanos = |d: &Duration| d.as_nanos() as f64;
+        let quantile = |q: f64| {
+            let position = q * (n - 1) as f64;
+            let (low, high) = (position.floor() as usize, position.ceil() as usize);
+            nanos(&samples[low])
+                + (position - low as f64) * (nanos(&samples[high]) - nanos(&samples[low]))
+        };
+
+        let mean = samples.iter().map(nanos).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            samples
+                .iter()
+                .map(|s| (nanos(s) - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        let (q1, q3) = (quantile(0.25), quantile(0.75));
+        let iqr = q3 - q1;
+        let (low_fence, high_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        let outliers = samples
+            .iter()
+            .filter(|s| nanos(s) < low_fence || nanos(s) > high_fence)
+            .count();
+
+        Some(Self {
+            samples: n,
+            mean: Duration::from_nanos(mean.round() as u64),
+            median: Duration::from_nanos(quantile(0.5).round() as u64),
+            min: samples[0],
+            max: samples[n - 1],
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            outliers,
+        })
+    }
+}
+
+/// Keeps the current thread pinned to a CPU, restoring its previous affinity when dropped.
+pub struct CpuPin {
+    #[cfg(target_os = "linux")]
+    previous: libc::cpu_set_t,
+}
+
+#[cfg(target_os = "linux")]
+fn get_affinity() -> io::Result<libc::cpu_set_t> {
+    // SAFETY: the CPU set is zero-initialized and filled by the kernel.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        if libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(set)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn set_affinity(set: &libc::cpu_set_t) -> io::Result<()> {
+    // SAFETY: the CPU set is a valid, initialized set.
+    if unsafe { libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), set) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Pins the current thread to the given CPU, until the returned [`CpuPin`] is dropped.
+#[cfg(target_os = "linux")]
+pub fn pin_to_cpu(cpu: usize) -> io::Result<CpuPin> {
+    if cpu >= CPU_COUNT {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "CPU {} is out of range, expecting a CPU below {}",
+                cpu, CPU_COUNT
+            ),
+        ));
+    }
+    let previous = get_affinity()?;
+    // SAFETY: the CPU set is zero-initialized and only modified through the libc macros.
+    let set = unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_ZERO(&mut set);
+        libc::CPU_SET(cpu, &mut set);
+        set
+    };
+    set_affinity(&set)?;
+
+    Ok(CpuPin { previous })
+}
+
+/// Pins the current thread to the given CPU, until the returned [`CpuPin`] is dropped.
+#[cfg(not(target_os = "linux"))]
+pub fn pin_to_cpu(_cpu: usize) -> io::Result<CpuPin> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "CPU pinning is only supported on Linux",
+    ))
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for CpuPin {
+    fn drop(&mut self) {
+        if let Err(e) = set_affinity(&self.previous) {
+            eprintln!("🫎 Failed to restore the CPU affinity: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = [10, 12, 11, 13, 12, 11, 10, 100]
+            .into_iter()
+            .map(Duration::from_nanos)
+            .collect();
+        let stats = Stats::from_samples(&mut samples).unwrap();
+
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.mean, Duration::from_nanos(22));
+        assert_eq!(stats.median, Duration::from_nanos(12));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(100));
+        assert_eq!(stats.std_dev, Duration::from_nanos(31));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_pin_out_of_range() {
+        assert!(pin_to_cpu(CPU_COUNT).is_err());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_pin_restores_affinity() {
+        let cpus = |set: &libc::cpu_set_t| -> Vec<usize> {
+            // SAFETY: the CPU set is initialized and only read.
+            (0..CPU_COUNT)
+                .filter(|cpu| unsafe { libc::CPU_ISSET(*cpu, set) })
+                .collect()
+        };
+        let before = cpus(&get_affinity().unwrap());
+
+        let pin = pin_to_cpu(before[0]).unwrap();
+        assert_eq!(cpus(&get_affinity().unwrap()), vec![before[0]]);
+        drop(pin);
+        assert_eq!(cpus(&get_affinity().unwrap()), before);
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    collections::BTreeMap,
+    fs::{read, read_to_string, write},
+    io,
+    path::PathBuf,
+};
+
+use sha2::{Digest, Sha256};
+
+use super::{data_path, Day};
+
+/// Checksums of the downloaded inputs of a year, in `data/inputs/<year>/SHA256SUMS`.
+///
+/// The manifest uses the format of `sha256sum`, so it can also be checked with `sha256sum -c`.
+pub struct Manifest {
+    path: PathBuf,
+    checksums: BTreeMap<String, String>,
+}
+
+fn input_filename(day: Day) -> String {
+    format!("{}.txt", day)
+}
+
+pub fn sha256(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+impl Manifest {
+    pub fn load(year: u16) -> io::Result<Self> {
+        let path = data_path("inputs", year, "SHA256SUMS");
+        let checksums = match read_to_string(&path) {
+            Ok(content) => content
+                .lines()
+                .filter_map(|line| line.split_once("  "))
+                .map(|(checksum, filename)| (filename.to_string(), checksum.to_string()))
+                .collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self { path, checksums })
+    }
+
+    pub fn get(&self, day: Day) -> Option<&str> {
+        self.checksums.get(&input_filename(day)).map(String::as_str)
+    }
+
+    /// Records the checksum of the input of a day.
+    pub fn insert(&mut self, day: Day, content: &[u8]) {
+        self.checksums.insert(input_filename(day), sha256(content));
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let content: String = self
+            .checksums
+            .iter()
+            .map(|(filename, checksum)| format!("{}  {}\n", checksum, filename))
+            .collect();
+        write(&self.path, content)
+    }
+}
+
+/// Checks the input file of a day against its recorded checksum.
+///
+/// Returns `false` only if the file does not match a recorded checksum, inputs that were not
+/// downloaded (or before the manifest existed) are not checked.
+pub fn verify_input(year: u16, day: Day) -> bool {
+    let Ok(manifest) = Manifest::load(year) else {
+        return true;
+    };
+    match (
+        manifest.get(day),
+        read(data_path("inputs", year, &input_filename(day))),
+    ) {
+        (Some(checksum), Ok(content)) => sha256(&content) == checksum,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            sha256(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
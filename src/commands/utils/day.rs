@@ -1,7 +1,7 @@
 use std::str::FromStr;
 use std::{error::Error, fmt::Display};
 
-use chrono::{Datelike, Local};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Day(u8);
@@ -16,28 +16,16 @@ impl Day {
         }
     }
 
+    /// Returns every day from 1 to 25, in order. See [`Event::days`](super::Event::days) for the
+    /// days of a given event.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (1..=25).map(Self)
+    }
+
     #[doc(hidden)]
     pub const fn __new_unchecked(day: u8) -> Self {
         Self(day)
     }
-
-    /// Returns the current day, or [`None`] if the current day is not a valid day for AoC.
-    pub fn today() -> Option<Self> {
-        let today = Local::now();
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(today.day() as u8)
-        } else {
-            None
-        }
-    }
-
-    /// Returns the current day, or panics if the current day is not a valid day for AoC.
-    pub fn today_safe() -> Self {
-        Self::today().unwrap_or_else(|| {
-            eprintln!("🫎 Today is not a valid day for AoC");
-            std::process::exit(1);
-        })
-    }
 }
 
 impl From<Day> for u8 {
@@ -70,6 +58,19 @@ impl PartialOrd<u8> for Day {
     }
 }
 
+impl Serialize for Day {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let day = u8::deserialize(deserializer)?;
+        Self::new(day).ok_or_else(|| de::Error::custom(DayFromStrError))
+    }
+}
+
 impl FromStr for Day {
     type Err = DayFromStrError;
 
@@ -90,6 +91,59 @@ impl Display for DayFromStrError {
     }
 }
 
+/// An inclusive range of days, parsed from `N` or `N-M`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayRange {
+    pub first: Day,
+    pub last: Day,
+}
+
+impl DayRange {
+    pub fn contains(&self, day: Day) -> bool {
+        self.first <= day && day <= self.last
+    }
+
+    /// Returns the day if the range only contains a single day.
+    pub fn single(&self) -> Option<Day> {
+        (self.first == self.last).then_some(self.first)
+    }
+}
+
+impl From<Day> for DayRange {
+    fn from(day: Day) -> Self {
+        Self {
+            first: day,
+            last: day,
+        }
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, last) = s.split_once('-').unwrap_or((s, s));
+        let first = first.parse().map_err(|_| DayRangeFromStrError)?;
+        let last = last.parse().map_err(|_| DayRangeFromStrError)?;
+        if first > last {
+            return Err(DayRangeFromStrError);
+        }
+
+        Ok(Self { first, last })
+    }
+}
+
+#[derive(Debug)]
+pub struct DayRangeFromStrError;
+
+impl Error for DayRangeFromStrError {}
+
+impl Display for DayRangeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("🫎 Expected a day or an increasing range of days, such as `1-25`")
+    }
+}
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
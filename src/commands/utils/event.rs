@@ -0,0 +1,162 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use chrono::{DateTime, Datelike, FixedOffset, Utc};
+
+use super::Day;
+
+const FIRST_YEAR: u16 = 2015;
+/// The first event with 12 puzzles instead of 25.
+const SHORT_EVENT_YEAR: u16 = 2025;
+/// Puzzles unlock at midnight EST (UTC-5), which sets the current event and day.
+const UNLOCK_UTC_OFFSET: i32 = -5 * 3600;
+
+/// Returns the given instant in the time zone of the puzzle unlocks.
+fn unlock_zone(now: DateTime<Utc>) -> DateTime<FixedOffset> {
+    now.with_timezone(&FixedOffset::east_opt(UNLOCK_UTC_OFFSET).unwrap())
+}
+
+/// An Advent of Code event, which sets the days that have a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Event(u16);
+
+impl Event {
+    /// Creates a new [`Event`], but returns [`None`] if there was no event that year.
+    pub fn new(year: u16) -> Option<Self> {
+        (year >= FIRST_YEAR).then_some(Self(year))
+    }
+
+    /// Returns the last event, the current one during December.
+    pub fn latest() -> Self {
+        Self::latest_at(Utc::now())
+    }
+
+    fn latest_at(now: DateTime<Utc>) -> Self {
+        let now = unlock_zone(now);
+        let year = if now.month() == 12 {
+            now.year()
+        } else {
+            now.year() - 1
+        };
+        Self(year.try_into().unwrap())
+    }
+
+    pub fn year(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the number of puzzles of the event: 25 until 2024, 12 since 2025.
+    pub fn puzzles(self) -> u8 {
+        if self.0 >= SHORT_EVENT_YEAR {
+            12
+        } else {
+            25
+        }
+    }
+
+    pub fn contains(self, day: Day) -> bool {
+        day <= self.puzzles()
+    }
+
+    /// Returns every day of the event, in order.
+    pub fn days(self) -> impl Iterator<Item = Day> {
+        Day::all().take(self.puzzles().into())
+    }
+
+    /// Returns the current day, or [`None`] if it is not a day of this event.
+    pub fn today(self) -> Option<Day> {
+        self.day_at(Utc::now())
+    }
+
+    fn day_at(self, now: DateTime<Utc>) -> Option<Day> {
+        let today = unlock_zone(now);
+        if today.year() != i32::from(self.0) || today.month() != 12 {
+            return None;
+        }
+        Day::new(today.day() as u8).filter(|day| self.contains(*day))
+    }
+
+    /// Returns the current day, or exits if it is not a day of this event.
+    pub fn today_safe(self) -> Day {
+        self.today().unwrap_or_else(|| {
+            eprintln!("🫎 Today is not a day of the {} event, give a day", self.0);
+            std::process::exit(1);
+        })
+    }
+
+    /// Returns the given day (or the current day if none is given), or exits if it is not a day
+    /// of this event.
+    pub fn day_safe(self, day: Option<Day>) -> Day {
+        match day {
+            Some(day) if !self.contains(day) => {
+                eprintln!(
+                    "🫎 Day {} does not exist in {}, the event has {} days",
+                    u8::from(day),
+                    self.0,
+                    self.puzzles()
+                );
+                std::process::exit(1);
+            }
+            Some(day) => day,
+            None => self.today_safe(),
+        }
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Event {
+    type Err = EventFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| EventFromStrError)?;
+        Self::new(year).ok_or(EventFromStrError)
+    }
+}
+
+#[derive(Debug)]
+pub struct EventFromStrError;
+
+impl Error for EventFromStrError {}
+
+impl Display for EventFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "🫎 Expected a year since {}", FIRST_YEAR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_days() {
+        let day = |day| Day::new(day).unwrap();
+
+        assert_eq!(Event::new(2024).unwrap().days().count(), 25);
+        assert!(Event::new(2024).unwrap().contains(day(13)));
+        assert_eq!(Event::new(2025).unwrap().days().last(), Some(day(12)));
+        assert!(!Event::new(2025).unwrap().contains(day(13)));
+        assert!("2014".parse::<Event>().is_err());
+    }
+
+    #[test]
+    fn test_event_today() {
+        use chrono::TimeZone;
+
+        // The first puzzle of 2024 unlocked at 05:00 UTC, when it was still November in EST.
+        let before = Utc.with_ymd_and_hms(2024, 12, 1, 4, 59, 59).unwrap();
+        let after = Utc.with_ymd_and_hms(2024, 12, 1, 5, 0, 0).unwrap();
+        assert_eq!(Event::latest_at(before), Event(2023));
+        assert_eq!(Event::latest_at(after), Event(2024));
+        assert_eq!(Event(2024).day_at(before), None);
+        assert_eq!(Event(2024).day_at(after), Day::new(1));
+
+        let last = Utc.with_ymd_and_hms(2025, 1, 1, 4, 0, 0).unwrap();
+        assert_eq!(Event::latest_at(last), Event(2024));
+        assert_eq!(Event(2024).day_at(last), None);
+    }
+}
@@ -1,6 +1,11 @@
-use std::{env, fs::read_to_string};
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, write},
+    io,
+    path::PathBuf,
+};
 
-use super::Day;
+use super::{checksum::verify_input, Day};
 
 #[must_use]
 #[allow(dead_code)]
@@ -14,25 +19,134 @@ pub fn read_example(year: u16, day: Day, part: u8) -> String {
     read_file("examples", year, day, Some(part))
 }
 
-fn read_file(folder: &str, year: u16, day: Day, part: Option<u8>) -> String {
+/// Where the input given to a solution comes from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, in `data/inputs/<year>/<day>.txt`.
+    #[default]
+    Input,
+    /// An example, in `data/examples/<year>/<day>-<N>.txt`.
+    Example(u8),
+    /// An arbitrary file.
+    File(PathBuf),
+    /// The standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// Creates the source of the `--example <N>` and `--input <path|->` options, `-` being the
+    /// standard input.
+    pub fn new(example: Option<u8>, input: Option<PathBuf>) -> Self {
+        match (example, input) {
+            (Some(example), _) => Self::Example(example),
+            (None, Some(path)) if path.as_os_str() == "-" => Self::Stdin,
+            (None, Some(path)) => Self::File(path),
+            (None, None) => Self::Input,
+        }
+    }
+
+    /// Parses the `--example <N>` and `--input <path|->` options of the solution binaries.
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let (mut example, mut input) = (None, None);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--example" => {
+                    let value = args.next().ok_or("Missing value for --example")?;
+                    example = Some(value.parse().map_err(|_| "Invalid example number")?);
+                }
+                "--input" => input = Some(args.next().ok_or("Missing value for --input")?.into()),
+                _ => return Err(format!("Unexpected argument `{}`", arg)),
+            }
+        }
+        if example.is_some() && input.is_some() {
+            return Err("--example and --input cannot be used together".to_string());
+        }
+
+        Ok(Self::new(example, input))
+    }
+
+    pub fn read(&self, year: u16, day: Day) -> io::Result<String> {
+        let content = match self {
+            Self::Input => {
+                if !verify_input(year, day) {
+                    eprintln!(
+                        "🫎 Input file of day {} does not match its downloaded checksum, it may have been edited or truncated (`download --force` fetches it again)",
+                        day
+                    );
+                }
+                return try_read_file("inputs", year, day, None);
+            }
+            Self::Example(part) => return try_read_file("examples", year, day, Some(*part)),
+            Self::File(path) => read_to_string(path)?,
+            Self::Stdin => io::read_to_string(io::stdin())?,
+        };
+        Ok(content.trim_end_matches('\n').to_string())
+    }
+}
+
+/// Returns the path of the `data/<folder>` directory.
+pub(crate) fn data_dir(folder: &str) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join("data").join(folder)
+}
+
+/// Returns the path of a file in the `data/<folder>/<year>` directory.
+pub(crate) fn data_path(folder: &str, year: u16, filename: &str) -> PathBuf {
+    data_dir(folder).join(year.to_string()).join(filename)
+}
+
+/// Saves the answer of the last run of a solution part, to be picked up by `submit`.
+pub(crate) fn save_last_answer(year: u16, day: Day, part: u8, answer: &str) -> io::Result<()> {
+    let path = data_path("last_run", year, &format!("{}-{}.txt", day, part));
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(path, answer)
+}
+
+/// Reads the answer of the last run of a solution part, if any.
+pub(crate) fn read_last_answer(year: u16, day: Day, part: u8) -> Option<String> {
+    let path = data_path("last_run", year, &format!("{}-{}.txt", day, part));
+    read_to_string(path).ok()
+}
+
+fn try_read_file(folder: &str, year: u16, day: Day, part: Option<u8>) -> io::Result<String> {
     let filename = match part {
         Some(part) => format!("{}-{}.txt", day, part),
         None => format!("{}.txt", day),
     };
 
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(year.to_string())
-        .join(filename);
-    let f = read_to_string(filepath);
-
-    match f {
-        Ok(f) => f.trim_end_matches('\n').to_string(),
+    let f = read_to_string(data_path(folder, year, &filename))?;
+    Ok(f.trim_end_matches('\n').to_string())
+}
+
+fn read_file(folder: &str, year: u16, day: Day, part: Option<u8>) -> String {
+    match try_read_file(folder, year, day, part) {
+        Ok(f) => f,
         Err(e) => {
             eprintln!("🫎 Could not open input file: {}", e);
             std::process::exit(1);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source_from_args() {
+        let args = |args: &[&str]| InputSource::from_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(args(&[]), Ok(InputSource::Input));
+        assert_eq!(args(&["--example", "2"]), Ok(InputSource::Example(2)));
+        assert_eq!(args(&["--input", "-"]), Ok(InputSource::Stdin));
+        assert_eq!(
+            args(&["--input", "big.txt"]),
+            Ok(InputSource::File("big.txt".into()))
+        );
+        assert!(args(&["--example"]).is_err());
+        assert!(args(&["--release"]).is_err());
+        assert!(args(&["--example", "1", "--input", "-"]).is_err());
+    }
+}
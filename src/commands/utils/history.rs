@@ -0,0 +1,153 @@
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{data_path, report::nanos, Day, PartResult};
+
+/// Environment variable holding the regression threshold, in percent.
+pub const REGRESSION_THRESHOLD_ENV_VAR: &str = "AOC_REGRESSION_THRESHOLD";
+
+/// Default regression threshold, in percent.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+/// A benchmark run of a solution part, as stored in `data/benchmarks/<year>/<day>.jsonl`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    pub time: DateTime<Utc>,
+    pub part: u8,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Option<Duration>,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Option<Duration>,
+    pub samples: usize,
+    #[serde(default, rename = "median_ns", with = "nanos")]
+    pub median: Option<Duration>,
+    #[serde(default, rename = "max_ns", with = "nanos")]
+    pub max: Option<Duration>,
+    #[serde(default, rename = "std_dev_ns", with = "nanos")]
+    pub std_dev: Option<Duration>,
+    pub rustc: String,
+    pub commit: String,
+    pub cpu: String,
+}
+
+/// The environment the benchmarks are run in.
+struct Environment {
+    rustc: String,
+    commit: String,
+    cpu: String,
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn environment() -> &'static Environment {
+    static ENVIRONMENT: OnceLock<Environment> = OnceLock::new();
+    ENVIRONMENT.get_or_init(|| {
+        let rustc = command_output("rustc", &["--version"]).unwrap_or_default();
+
+        let mut commit =
+            command_output("git", &["rev-parse", "--short", "HEAD"]).unwrap_or_default();
+        if command_output("git", &["status", "--porcelain", "--untracked-files=no"])
+            .is_some_and(|status| !status.is_empty())
+        {
+            commit.push_str("-dirty");
+        }
+
+        let cpu = read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|cpuinfo| {
+                cpuinfo
+                    .lines()
+                    .find(|line| line.starts_with("model name"))
+                    .and_then(|line| line.split_once(':'))
+                    .map(|(_, model)| model.trim().to_string())
+            })
+            .unwrap_or_else(|| env::consts::ARCH.to_string());
+
+        Environment { rustc, commit, cpu }
+    })
+}
+
+/// Reads every benchmark run recorded for a given day.
+pub fn load(year: u16, day: Day) -> io::Result<Vec<BenchmarkRecord>> {
+    let path = data_path("benchmarks", year, &format!("{}.jsonl", day));
+    match read_to_string(path) {
+        Ok(content) => Ok(content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Fills in the last and best recorded mean times of a part, then appends its result to the
+/// benchmark history.
+pub fn record(result: &mut PartResult) -> io::Result<()> {
+    let history = load(result.year, result.day)?;
+    let previous = history
+        .iter()
+        .filter(|record| record.part == result.part)
+        .filter_map(|record| record.mean);
+    result.last = previous.clone().next_back();
+    result.best = previous.min();
+
+    let environment = environment();
+    let record = BenchmarkRecord {
+        time: Utc::now(),
+        part: result.part,
+        mean: result.mean,
+        min: result.min,
+        samples: result.samples,
+        median: result.median,
+        max: result.max,
+        std_dev: result.std_dev,
+        rustc: environment.rustc.clone(),
+        commit: environment.commit.clone(),
+        cpu: environment.cpu.clone(),
+    };
+
+    let path = data_path("benchmarks", result.year, &format!("{}.jsonl", result.day));
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(&record).unwrap())
+}
+
+/// Reads the regression threshold (in percent) from the `AOC_REGRESSION_THRESHOLD` environment
+/// variable.
+pub fn regression_threshold() -> f64 {
+    env::var(REGRESSION_THRESHOLD_ENV_VAR)
+        .ok()
+        .and_then(|threshold| threshold.parse().ok())
+        .unwrap_or(DEFAULT_REGRESSION_THRESHOLD)
+}
+
+/// Relative difference between a time and a reference time, in percent.
+pub fn delta(time: Duration, reference: Duration) -> f64 {
+    (time.as_secs_f64() / reference.as_secs_f64() - 1.0) * 100.0
+}
+
+/// Formats a relative difference, in red if it is a regression above the threshold.
+pub fn format_delta(delta: f64, threshold: f64) -> String {
+    if delta > threshold {
+        format!("\x1b[31m{:+.1}%\x1b[0m", delta)
+    } else {
+        format!("{:+.1}%", delta)
+    }
+}
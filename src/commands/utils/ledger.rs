@@ -0,0 +1,217 @@
+use std::{
+    fmt::Display,
+    fs::{create_dir_all, read_to_string, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use chrono::{DateTime, Utc};
+
+use super::{aoc::Verdict, data_path, Day};
+
+/// A single answer submission and its verdict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub time: DateTime<Utc>,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Renders the attempt as a tab-separated line of the ledger file.
+impl Display for Attempt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}",
+            self.time.to_rfc3339(),
+            self.part,
+            self.verdict,
+            self.answer
+        )
+    }
+}
+
+impl Attempt {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(4, '\t');
+        Some(Self {
+            time: DateTime::parse_from_rfc3339(fields.next()?).ok()?.into(),
+            part: fields.next()?.parse().ok()?,
+            verdict: fields.next()?.parse().ok()?,
+            answer: fields.next()?.to_string(),
+        })
+    }
+}
+
+/// Reason for refusing to submit an answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong(Verdict),
+    NotBelow(String),
+    NotAbove(String),
+    Wait(i64),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadySolved(answer) => write!(f, "Part already solved with answer {}", answer),
+            Self::KnownWrong(verdict) => write!(f, "Answer already submitted ({})", verdict),
+            Self::NotBelow(bound) => write!(f, "Answer must be lower than {} (too high)", bound),
+            Self::NotAbove(bound) => write!(f, "Answer must be higher than {} (too low)", bound),
+            Self::Wait(seconds) => write!(f, "Wait {}s before submitting again", seconds),
+        }
+    }
+}
+
+/// Local record of every answer submitted for a given day.
+///
+/// The ledger is stored in `data/submissions/<year>/<day>.txt`, one attempt per line.
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    pub fn load(year: u16, day: Day) -> io::Result<Self> {
+        let path = data_path("submissions", year, &format!("{}.txt", day));
+        let attempts = match read_to_string(&path) {
+            Ok(content) => content.lines().filter_map(Attempt::parse).collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self { path, attempts })
+    }
+
+    pub fn attempts(&self, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts.iter().filter(move |a| a.part == part)
+    }
+
+    /// Checks whether an answer is worth submitting, given the previous attempts.
+    pub fn check(&self, part: u8, answer: &str, now: DateTime<Utc>) -> Result<(), Refusal> {
+        let value = answer.parse::<i128>().ok();
+        let mut lowest_too_high: Option<(i128, &str)> = None;
+        let mut highest_too_low: Option<(i128, &str)> = None;
+
+        for attempt in self.attempts(part) {
+            match attempt.verdict {
+                Verdict::Correct => return Err(Refusal::AlreadySolved(attempt.answer.clone())),
+                Verdict::Wait(seconds) => {
+                    let remaining = seconds as i64 - (now - attempt.time).num_seconds();
+                    if remaining > 0 {
+                        return Err(Refusal::Wait(remaining));
+                    }
+                }
+                verdict if verdict.is_wrong() && attempt.answer == answer => {
+                    return Err(Refusal::KnownWrong(verdict));
+                }
+                _ => {}
+            }
+
+            if let Ok(bound) = attempt.answer.parse::<i128>() {
+                match attempt.verdict {
+                    Verdict::TooHigh if lowest_too_high.is_none_or(|(b, _)| bound < b) => {
+                        lowest_too_high = Some((bound, &attempt.answer));
+                    }
+                    Verdict::TooLow if highest_too_low.is_none_or(|(b, _)| bound > b) => {
+                        highest_too_low = Some((bound, &attempt.answer));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if let Some(value) = value {
+            match (lowest_too_high, highest_too_low) {
+                (Some((bound, answer)), _) if value >= bound => {
+                    return Err(Refusal::NotBelow(answer.to_string()));
+                }
+                (_, Some((bound, answer))) if value <= bound => {
+                    return Err(Refusal::NotAbove(answer.to_string()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends an attempt to the ledger file.
+    pub fn record(&mut self, attempt: Attempt) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", attempt)?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            time: DateTime::parse_from_rfc3339("2024-12-05T05:10:00Z")
+                .unwrap()
+                .into(),
+            part,
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_attempt_round_trip() {
+        let attempt = attempt(2, "1234", Verdict::Wait(65));
+        assert_eq!(Attempt::parse(&attempt.to_string()), Some(attempt));
+    }
+
+    #[test]
+    fn test_check() {
+        let ledger = Ledger {
+            path: PathBuf::new(),
+            attempts: vec![
+                attempt(1, "100", Verdict::TooLow),
+                attempt(1, "500", Verdict::TooHigh),
+                attempt(1, "300", Verdict::Incorrect),
+                attempt(2, "42", Verdict::Correct),
+            ],
+        };
+        let now = ledger.attempts[0].time + TimeDelta::minutes(10);
+
+        assert_eq!(ledger.check(1, "250", now), Ok(()));
+        assert_eq!(ledger.check(1, "abc", now), Ok(()));
+        assert_eq!(
+            ledger.check(1, "300", now),
+            Err(Refusal::KnownWrong(Verdict::Incorrect))
+        );
+        assert_eq!(
+            ledger.check(1, "600", now),
+            Err(Refusal::NotBelow("500".to_string()))
+        );
+        assert_eq!(
+            ledger.check(1, "100", now),
+            Err(Refusal::KnownWrong(Verdict::TooLow))
+        );
+        assert_eq!(
+            ledger.check(1, "50", now),
+            Err(Refusal::NotAbove("100".to_string()))
+        );
+        assert_eq!(
+            ledger.check(2, "43", now),
+            Err(Refusal::AlreadySolved("42".to_string()))
+        );
+    }
+}
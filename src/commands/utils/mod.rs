@@ -1,15 +1,34 @@
+pub mod alloc;
+pub mod answers;
 pub mod aoc;
+pub mod bench;
+pub mod checksum;
 mod day;
+mod event;
 mod file;
+pub mod history;
+pub mod ledger;
+pub mod profile;
+pub mod puzzle;
+mod report;
 mod runner;
+pub mod transport;
 
-pub use day::Day;
-pub use file::{read_example, read_input};
-pub use runner::run_part;
+pub use answers::read_answer;
+pub use day::{Day, DayRange};
+pub use event::Event;
+pub(crate) use file::{data_dir, data_path, read_last_answer};
+pub use file::{read_example, read_input, InputSource};
+pub use report::{reporter, PartResult, ReportFormat, Reporter, PARSE_PART, REPORT_ENV_VAR};
+pub use runner::{run_main, run_parse, run_part, RunOptions, Solution};
 
 /// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// A `parse = <function>` parameter declares a function parsing the input once for both parts,
+/// which then take a reference to its output instead of the raw input. The parsing step is timed
+/// (and benchmarked) separately from the parts.
 #[macro_export]
 macro_rules! solution {
     ($year:expr, $day:expr) => {
@@ -21,18 +40,36 @@ macro_rules! solution {
     ($year:expr, $day:expr, 2) => {
         $crate::solution!(@impl $year, $day, [part_two, 2]);
     };
+    ($year:expr, $day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl $year, $day, parse = $parse, [part_one, 1] [part_two, 2]);
+    };
+    ($year:expr, $day:expr, parse = $parse:expr, 1) => {
+        $crate::solution!(@impl $year, $day, parse = $parse, [part_one, 1]);
+    };
+    ($year:expr, $day:expr, parse = $parse:expr, 2) => {
+        $crate::solution!(@impl $year, $day, parse = $parse, [part_two, 2]);
+    };
 
-    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $year:expr, $day:expr, $( parse = $parse:expr, )? $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::commands::Day = $crate::day!($day);
 
         /// The current year.
         const YEAR: u16 = $year;
 
+        /// Runs the solution parts on an input.
+        pub fn run(
+            input: &str,
+            reporter: &mut dyn $crate::commands::Reporter,
+            options: &$crate::commands::RunOptions,
+        ) {
+            use $crate::commands::{run_parse, run_part};
+            $( let input = run_parse($parse, input, YEAR, DAY, reporter, options); )?
+            $( run_part($func, &input, YEAR, DAY, $part, reporter, options); )*
+        }
+
         fn main() {
-            use $crate::commands::run_part;
-            let input = $crate::commands::read_input(YEAR, DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            $crate::commands::run_main(YEAR, DAY, run);
         }
     };
 }
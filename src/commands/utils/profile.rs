@@ -0,0 +1,227 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Whether spans are recorded, only during the instrumented run of `solve --profile`.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static PROFILER: RefCell<Profiler> = RefCell::new(Profiler::default());
+}
+
+struct Node {
+    name: &'static str,
+    children: Vec<usize>,
+    total: Duration,
+    calls: u32,
+}
+
+/// The tree of the spans entered since [`start`], the root being the node 0.
+struct Profiler {
+    nodes: Vec<Node>,
+    /// The open spans, with the time they were entered.
+    stack: Vec<(usize, Instant)>,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node {
+                name: "",
+                children: Vec::new(),
+                total: Duration::ZERO,
+                calls: 0,
+            }],
+            stack: Vec::new(),
+        }
+    }
+}
+
+impl Profiler {
+    fn enter(&mut self, name: &'static str) {
+        let parent = self.stack.last().map_or(0, |(node, _)| *node);
+        // Spans entered several times within the same parent (e.g. in a loop) are aggregated.
+        let existing = self.nodes[parent]
+            .children
+            .iter()
+            .copied()
+            .find(|child| self.nodes[*child].name == name);
+        let node = existing.unwrap_or_else(|| {
+            self.nodes.push(Node {
+                name,
+                children: Vec::new(),
+                total: Duration::ZERO,
+                calls: 0,
+            });
+            let node = self.nodes.len() - 1;
+            self.nodes[parent].children.push(node);
+            node
+        });
+        self.stack.push((node, Instant::now()));
+    }
+
+    fn exit(&mut self) {
+        if let Some((node, start)) = self.stack.pop() {
+            self.nodes[node].total += start.elapsed();
+            self.nodes[node].calls += 1;
+        }
+    }
+
+    fn timings(&self, node: usize, depth: usize, timings: &mut Vec<SpanTiming>) {
+        for &child in &self.nodes[node].children {
+            let Node {
+                name, total, calls, ..
+            } = self.nodes[child];
+            timings.push(SpanTiming {
+                name: name.into(),
+                depth,
+                total,
+                calls,
+            });
+            self.timings(child, depth + 1, timings);
+        }
+    }
+}
+
+/// The time spent in a span, summed over its calls within the same parent span.
+///
+/// Names are owned once the timings are read back from a child process (see `solve --timeout`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpanTiming {
+    pub name: Cow<'static, str>,
+    /// Number of parent spans.
+    pub depth: usize,
+    pub total: Duration,
+    pub calls: u32,
+}
+
+/// A guard recording the time until it is dropped, created by [`span!`](crate::span).
+#[must_use = "the span ends when the guard is dropped, bind it with `let _span = ...`"]
+pub struct Span(bool);
+
+impl Span {
+    /// Enters a span, if profiling. Otherwise, only an atomic load is done, so that spans can stay
+    /// in the benchmarked code.
+    #[inline]
+    pub fn enter(name: &'static str) -> Self {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return Self(false);
+        }
+        PROFILER.with_borrow_mut(|profiler| profiler.enter(name));
+        Self(true)
+    }
+}
+
+impl Drop for Span {
+    #[inline]
+    fn drop(&mut self) {
+        if self.0 {
+            PROFILER.with_borrow_mut(Profiler::exit);
+        }
+    }
+}
+
+/// Starts recording the spans of the current thread.
+pub fn start() {
+    PROFILER.with_borrow_mut(|profiler| *profiler = Profiler::default());
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stops recording the spans, and returns their timings in depth-first order.
+pub fn finish() -> Vec<SpanTiming> {
+    ENABLED.store(false, Ordering::Relaxed);
+    PROFILER.with_borrow(|profiler| {
+        let mut timings = Vec::new();
+        profiler.timings(0, 0, &mut timings);
+        timings
+    })
+}
+
+/// Renders the timing tree of a run, with the share of each span in the total time of the run.
+pub fn render(timings: &[SpanTiming], total: Duration) -> Vec<String> {
+    let labels: Vec<String> = timings
+        .iter()
+        .map(|timing| match timing.calls {
+            1 => format!("{}{}", "  ".repeat(timing.depth), timing.name),
+            calls => format!("{}{} ×{}", "  ".repeat(timing.depth), timing.name, calls),
+        })
+        .collect();
+    let width = labels.iter().map(|label| label.chars().count()).max();
+
+    timings
+        .iter()
+        .zip(&labels)
+        .map(|(timing, label)| {
+            format!(
+                "{:<width$}  {:>10}  {:>5.1}%",
+                label,
+                format!("{:.1?}", timing.total),
+                timing.total.as_secs_f64() / total.as_secs_f64() * 100.0,
+                width = width.unwrap_or_default()
+            )
+        })
+        .collect()
+}
+
+/// Times the rest of the enclosing block as a span of `solve --profile`, nested in the spans
+/// entered before it.
+///
+/// ```ignore
+/// let _span = advent_of_code::span!("build graph");
+/// ```
+#[cfg(feature = "spans")]
+#[macro_export]
+macro_rules! span {
+    ($name:expr) => {
+        $crate::commands::profile::Span::enter($name)
+    };
+}
+
+/// Times the rest of the enclosing block as a span of `solve --profile`, nested in the spans
+/// entered before it.
+///
+/// Without the `spans` feature, spans compile to nothing.
+#[cfg(not(feature = "spans"))]
+#[macro_export]
+macro_rules! span {
+    ($name:expr) => {
+        ()
+    };
+}
+
+/// Returns whether spans are compiled in, with the `spans` feature.
+pub const fn enabled() -> bool {
+    cfg!(feature = "spans")
+}
+
+#[cfg(all(test, feature = "spans"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans() {
+        let _ignored = span!("ignored");
+        start();
+        {
+            let _outer = span!("outer");
+            for _ in 0..3 {
+                let _inner = span!("inner");
+            }
+        }
+        let _second = span!("second");
+        drop(_second);
+        let timings = finish();
+
+        let tree: Vec<(&str, usize, u32)> = timings
+            .iter()
+            .map(|timing| (&*timing.name, timing.depth, timing.calls))
+            .collect();
+        assert_eq!(tree, [("outer", 0, 1), ("inner", 1, 3), ("second", 0, 1)]);
+        assert!(timings[0].total >= timings[1].total);
+    }
+}
@@ -0,0 +1,492 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+    sync::LazyLock,
+};
+
+use aoc_client::AocResult;
+use regex::Regex;
+
+use super::{aoc::get_puzzle_html, data_path, Day};
+
+const RESET: &str = "\x1b[0m";
+const EMPHASIS: &str = "\x1b[1m";
+const STAR: &str = "\x1b[1;33m";
+const CODE: &str = "\x1b[32m";
+
+static TITLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<h2>--- Day \d+: (.*?) ---</h2>").unwrap());
+static ENTITIES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"&(#\d+|[a-z]+);").unwrap());
+static ESCAPE_SEQUENCES: LazyLock<Regex> = LazyLock::new(|| Regex::new("\x1b\\[[0-9;]*m").unwrap());
+static SECTIONS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<article[^>]*>.*?</article>|<p>Your puzzle answer was.*?</p>").unwrap()
+});
+static ARTICLES: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+static CODE_BLOCKS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+static ANSWERS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<code><em>([^<]*)</em></code>").unwrap());
+
+/// Returns the path of the cached puzzle page of a day, in `data/puzzles/<year>/<day>.html`.
+pub fn puzzle_path(year: u16, day: Day) -> PathBuf {
+    data_path("puzzles", year, &format!("{}.html", day))
+}
+
+/// Returns whether the description of the second part is in the puzzle page.
+pub fn has_part_two(html: &str) -> bool {
+    html.matches("<article").count() >= 2
+}
+
+/// Returns the title of the puzzle, from the `--- Day N: Title ---` heading of its page.
+pub fn title(html: &str) -> Option<String> {
+    TITLE.captures(html).map(|c| decode_entities(&c[1]))
+}
+
+/// Returns the cached puzzle page of a day, if any.
+pub fn cached_puzzle(year: u16, day: Day) -> Option<String> {
+    read_to_string(puzzle_path(year, day)).ok()
+}
+
+/// Returns the puzzle page of a day, from the cache as long as it has the second part (unless
+/// `refresh` is set). Downloaded pages are cached, and the cached page is returned when the
+/// download fails.
+pub fn get_puzzle(year: u16, day: Day, refresh: bool) -> AocResult<String> {
+    let path = puzzle_path(year, day);
+    let cached = cached_puzzle(year, day);
+
+    match cached {
+        Some(html) if !refresh && has_part_two(&html) => Ok(html),
+        cached => match get_puzzle_html(year, day, refresh) {
+            Ok(html) => {
+                let saved = match path.parent() {
+                    Some(parent) => create_dir_all(parent).and_then(|_| write(&path, &html)),
+                    None => write(&path, &html),
+                };
+                if let Err(e) = saved {
+                    eprintln!("🫎 Failed to cache puzzle: {}", e);
+                }
+                Ok(html)
+            }
+            Err(e) => match cached {
+                Some(html) => {
+                    eprintln!("🫎 Failed to download puzzle, using the cached one: {}", e);
+                    Ok(html)
+                }
+                None => Err(e),
+            },
+        },
+    }
+}
+
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(String),
+}
+
+fn decode_entities(text: &str) -> String {
+    ENTITIES
+        .replace_all(text, |c: &regex::Captures| match &c[1] {
+            "lt" => "<".to_string(),
+            "gt" => ">".to_string(),
+            "amp" => "&".to_string(),
+            "quot" => "\"".to_string(),
+            "apos" => "'".to_string(),
+            entity => entity
+                .strip_prefix('#')
+                .and_then(|code| code.parse().ok())
+                .and_then(char::from_u32)
+                .map_or_else(|| c[0].to_string(), String::from),
+        })
+        .to_string()
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                // An unterminated tag is only text.
+                let Some(end) = rest.find('>').map(|end| end + 1) else {
+                    tokens.push(Token::Text(decode_entities(rest)));
+                    break;
+                };
+                let tag = rest[1..end - 1].trim_end_matches('/');
+                if let Some(name) = tag.strip_prefix('/') {
+                    tokens.push(Token::Close(name.trim()));
+                } else {
+                    let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+                    tokens.push(Token::Open(name, attributes));
+                }
+                rest = &rest[end..];
+            }
+            Some(start) => {
+                tokens.push(Token::Text(decode_entities(&rest[..start])));
+                rest = &rest[start..];
+            }
+            None => {
+                tokens.push(Token::Text(decode_entities(rest)));
+                rest = "";
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Length of a string, ignoring the ANSI escape sequences.
+fn visible_len(text: &str) -> usize {
+    ESCAPE_SEQUENCES.replace_all(text, "").chars().count()
+}
+
+/// Returns the escape sequences still in effect at the end of a line.
+fn active_style(line: &str) -> String {
+    let after_reset = line
+        .rfind(RESET)
+        .map_or(line, |reset| &line[reset + RESET.len()..]);
+    ESCAPE_SEQUENCES
+        .find_iter(after_reset)
+        .map(|sequence| sequence.as_str())
+        .collect()
+}
+
+#[derive(Default)]
+struct Renderer {
+    width: usize,
+    lines: Vec<String>,
+    /// Inline content of the current block, with its escape sequences.
+    block: String,
+    styles: Vec<&'static str>,
+    pre: bool,
+    depth: usize,
+    bullet: bool,
+}
+
+impl Renderer {
+    fn push_style(&mut self, style: &'static str) {
+        self.styles.push(style);
+        self.block += style;
+    }
+
+    fn pop_style(&mut self) {
+        self.styles.pop();
+        self.block += RESET;
+        self.block += &self.styles.concat();
+    }
+
+    fn blank_line(&mut self) {
+        if self.lines.last().is_some_and(|line| !line.is_empty()) {
+            self.lines.push(String::new());
+        }
+    }
+
+    /// Word-wraps the current block, indented according to the list depth.
+    fn flush(&mut self) {
+        let block = std::mem::take(&mut self.block);
+        let indent = "  ".repeat(self.depth);
+        let width = self
+            .width
+            .saturating_sub(indent.len() + if self.bullet { 2 } else { 0 });
+
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        for word in block.split(' ') {
+            if visible_len(word) == 0 {
+                line += word;
+                continue;
+            }
+            if visible_len(&line) > 0 && visible_len(&line) + 1 + visible_len(word) > width {
+                let style = active_style(&line);
+                lines.push(line);
+                line = style;
+            }
+            if visible_len(&line) > 0 {
+                line.push(' ');
+            }
+            line += word;
+        }
+        if visible_len(&line) > 0 {
+            lines.push(line);
+        }
+
+        for (index, line) in lines.into_iter().enumerate() {
+            let prefix = match (index, self.bullet) {
+                (0, true) => "• ",
+                (_, true) => "  ",
+                _ => "",
+            };
+            let reset = if active_style(&line).is_empty() {
+                ""
+            } else {
+                RESET
+            };
+            self.lines
+                .push(format!("{}{}{}{}", indent, prefix, line, reset));
+        }
+        self.bullet = false;
+    }
+
+    fn open(&mut self, name: &str, attributes: &str) {
+        match name {
+            "h2" => {
+                self.flush();
+                self.push_style(EMPHASIS);
+            }
+            "p" => self.flush(),
+            "ul" => {
+                self.flush();
+                self.depth += 1;
+            }
+            "li" => {
+                self.flush();
+                self.bullet = true;
+            }
+            "pre" => {
+                self.flush();
+                self.pre = true;
+            }
+            "em" if attributes.contains("star") => self.push_style(STAR),
+            "em" => self.push_style(EMPHASIS),
+            // Code blocks are styled as a whole.
+            "code" if !self.pre => self.push_style(CODE),
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "h2" => {
+                self.pop_style();
+                self.flush();
+                self.blank_line();
+            }
+            "p" => {
+                self.flush();
+                self.blank_line();
+            }
+            "ul" => {
+                self.flush();
+                self.depth -= 1;
+                self.blank_line();
+            }
+            "li" => self.flush(),
+            "pre" => {
+                let block = std::mem::take(&mut self.block);
+                for line in block.trim_end_matches('\n').lines() {
+                    self.lines.push(format!("    {}{}{}", CODE, line, RESET));
+                }
+                self.pre = false;
+                self.blank_line();
+            }
+            "em" => self.pop_style(),
+            "code" if !self.pre => self.pop_style(),
+            "article" => {
+                self.flush();
+                self.blank_line();
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.pre {
+            self.block += text;
+        } else {
+            let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if text.starts_with(char::is_whitespace) {
+                self.block.push(' ');
+            }
+            self.block += &collapsed;
+            if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                self.block.push(' ');
+            }
+        }
+    }
+}
+
+/// Renders the puzzle descriptions (and the answers already given) of a puzzle page as terminal
+/// text, wrapped to the given width.
+pub fn render(html: &str, width: usize) -> String {
+    let mut renderer = Renderer {
+        width,
+        ..Default::default()
+    };
+    for section in SECTIONS.find_iter(html) {
+        for token in tokenize(section.as_str()) {
+            match token {
+                Token::Open(name, attributes) => renderer.open(name, attributes),
+                Token::Close(name) => renderer.close(name),
+                Token::Text(text) => renderer.text(&text),
+            }
+        }
+    }
+    renderer.flush();
+
+    renderer.lines.join("\n").trim_end().to_string()
+}
+
+/// Examples found in a puzzle page.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    /// Every code block of the puzzle descriptions, without duplicates. The main example of the
+    /// first part comes first.
+    pub blocks: Vec<String>,
+    /// For each part, the number of its example (starting from 1) and the expected answer on it.
+    pub answers: [Option<(u8, String)>; 2],
+}
+
+/// Text of an HTML fragment, without its tags.
+fn strip_tags(html: &str) -> String {
+    tokenize(html)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Extracts the code blocks of a puzzle page, guessing which one is the main example of each part:
+/// the first block introduced by a sentence mentioning an example, or else the largest one. The
+/// expected answer of a part is the last emphasized code of its description.
+pub fn extract_examples(html: &str) -> Examples {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut mains: [Option<usize>; 2] = [None; 2];
+    let mut part_answers: [Option<String>; 2] = [None, None];
+    for (part, article) in ARTICLES.captures_iter(html).take(2).enumerate() {
+        let article = article.get(1).unwrap().as_str();
+        let (mut introduced, mut largest) = (None, None::<(usize, usize)>);
+        let mut previous_end = 0;
+
+        for block in CODE_BLOCKS.captures_iter(article) {
+            let whole = block.get(0).unwrap();
+            let text = strip_tags(&block[1]);
+            let before = strip_tags(&article[previous_end..whole.start()]).to_lowercase();
+            previous_end = whole.end();
+
+            let index = blocks.iter().position(|b| *b == text).unwrap_or_else(|| {
+                blocks.push(text.clone());
+                blocks.len() - 1
+            });
+            if introduced.is_none() && before.contains("example") {
+                introduced = Some(index);
+            }
+            if largest.is_none_or(|(_, length)| text.len() > length) {
+                largest = Some((index, text.len()));
+            }
+        }
+
+        mains[part] = introduced.or(largest.map(|(index, _)| index));
+        part_answers[part] = ANSWERS
+            .captures_iter(article)
+            .last()
+            .map(|answer| decode_entities(&answer[1]));
+    }
+
+    // The main example of the first part becomes the first example.
+    let first = mains[0].unwrap_or(0);
+    let number = |index: usize| match index {
+        _ if index == first => 1,
+        _ if index < first => index as u8 + 2,
+        _ => index as u8 + 1,
+    };
+    let mut examples = Examples::default();
+    for part in 0..2 {
+        let main = mains[part].or(mains[0]);
+        if let (Some(main), Some(answer)) = (main, part_answers[part].take()) {
+            examples.answers[part] = Some((number(main), answer));
+        }
+    }
+    if first < blocks.len() {
+        let main = blocks.remove(first);
+        blocks.insert(0, main);
+    }
+    examples.blocks = blocks;
+
+    examples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/puzzle-part-one.html"
+    ));
+    const PART_TWO: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/puzzle-part-two.html"
+    ));
+
+    fn plain(text: &str) -> String {
+        ESCAPE_SEQUENCES.replace_all(text, "").to_string()
+    }
+
+    #[test]
+    fn test_unterminated_tag() {
+        let tokens = tokenize("<p>a < b <");
+        assert!(matches!(tokens[0], Token::Open("p", "")));
+        let text: Vec<String> = tokens[1..]
+            .iter()
+            .map(|token| match token {
+                Token::Text(text) => text.clone(),
+                _ => panic!("expected text"),
+            })
+            .collect();
+        assert_eq!(text.concat(), "a < b <");
+    }
+
+    #[test]
+    fn test_render_part_one() {
+        let rendered = render(PART_ONE, 40);
+        let text = plain(&rendered);
+
+        assert!(!has_part_two(PART_ONE));
+        assert!(text.starts_with("--- Day 3: Sock Drawer ---\n\nThe elves have stuffed every"));
+        assert!(text.contains("\n    red 42\n    blue 38\n    red 42\n"));
+        assert!(text.contains("\n  • they have the same color, and\n"));
+        assert!(rendered.contains(&format!("{}unmatched", EMPHASIS)));
+        assert!(rendered.contains(&format!("{}{}1{}{}{}", CODE, EMPHASIS, RESET, CODE, RESET)));
+        assert!(rendered.contains(&format!("\n    {}blue 38{}\n", CODE, RESET)));
+        assert!(!text.contains("get your puzzle input"));
+        assert!(text.lines().all(|line| line.chars().count() <= 40));
+        assert!(rendered.lines().all(|line| active_style(line).is_empty()));
+    }
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(title(PART_TWO), Some("Sock Drawer".to_string()));
+        let examples = extract_examples(PART_TWO);
+        assert_eq!(examples.blocks, vec!["red 42\nblue 38\nred 42\n"]);
+        assert_eq!(
+            examples.answers,
+            [Some((1, "1".to_string())), Some((1, "1".to_string()))]
+        );
+
+        let html = "<article><p>Some <code>noise</code>:</p><pre><code>x</code></pre>\
+            <p>For example:</p><pre><code>a\n<em>b</em>\n</code></pre>\
+            <p>This gives <code><em>12</em></code>.</p></article>";
+        assert_eq!(
+            extract_examples(html),
+            Examples {
+                blocks: vec!["a\nb\n".to_string(), "x".to_string()],
+                answers: [Some((1, "12".to_string())), None],
+            }
+        );
+    }
+
+    #[test]
+    fn test_render_part_two() {
+        let text = plain(&render(PART_TWO, 80));
+
+        assert!(has_part_two(PART_TWO));
+        assert!(text.contains("Your puzzle answer was 512.\n\n--- Part Two ---"));
+        assert!(text.contains("Socks of sizes 41 & 42 fit the same elves <most of the time>."));
+        assert!(text.ends_with("Your puzzle answer was 640."));
+        assert!(!text.contains("Both parts of this puzzle are complete"));
+    }
+}
@@ -0,0 +1,539 @@
+use std::{
+    env,
+    fmt::Display,
+    io::{stdout, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    alloc::{format_bytes, Allocations},
+    bench::Stats,
+    history::{delta, format_delta, regression_threshold},
+    profile::{render, SpanTiming},
+    Day,
+};
+
+/// Environment variable used to select the reporter of the solution binaries.
+pub const REPORT_ENV_VAR: &str = "AOC_REPORT";
+
+/// Part number used for the results of the parsing step of a solution.
+pub const PARSE_PART: u8 = 0;
+
+/// Result of a solution part, as given to a [`Reporter`].
+///
+/// Timings are only set once the part has run, statistics other than the mean and minimum time
+/// are only set for benchmarked parts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Option<Duration>,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Option<Duration>,
+    pub samples: usize,
+    #[serde(
+        default,
+        rename = "median_ns",
+        skip_serializing_if = "Option::is_none",
+        with = "nanos"
+    )]
+    pub median: Option<Duration>,
+    #[serde(
+        default,
+        rename = "max_ns",
+        skip_serializing_if = "Option::is_none",
+        with = "nanos"
+    )]
+    pub max: Option<Duration>,
+    #[serde(
+        default,
+        rename = "std_dev_ns",
+        skip_serializing_if = "Option::is_none",
+        with = "nanos"
+    )]
+    pub std_dev: Option<Duration>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outliers: Option<usize>,
+    /// Verified answer of the part, from the answer store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// Set when the part could not be run to completion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Mean time of the last recorded benchmark run.
+    #[serde(
+        default,
+        rename = "last_ns",
+        skip_serializing_if = "Option::is_none",
+        with = "nanos"
+    )]
+    pub last: Option<Duration>,
+    /// Best mean time of every recorded benchmark run.
+    #[serde(
+        default,
+        rename = "best_ns",
+        skip_serializing_if = "Option::is_none",
+        with = "nanos"
+    )]
+    pub best: Option<Duration>,
+    /// Allocations of the first run, with the `count-alloc` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocated_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_bytes: Option<u64>,
+}
+
+impl PartResult {
+    /// Creates the result of a part that has not been run yet.
+    pub fn new(year: u16, day: Day, part: u8) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            mean: None,
+            min: None,
+            samples: 0,
+            median: None,
+            max: None,
+            std_dev: None,
+            outliers: None,
+            expected: None,
+            error: None,
+            last: None,
+            best: None,
+            allocations: None,
+            allocated_bytes: None,
+            peak_bytes: None,
+        }
+    }
+
+    /// Creates the result of a part that was run once.
+    pub fn timed(year: u16, day: Day, part: u8, duration: Duration) -> Self {
+        Self {
+            mean: Some(duration),
+            min: Some(duration),
+            samples: 1,
+            ..Self::new(year, day, part)
+        }
+    }
+
+    /// Creates the result of a part that could not be run.
+    pub fn failed(year: u16, day: Day, part: u8, error: &str) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::new(year, day, part)
+        }
+    }
+
+    /// Returns `Parse` for the parsing step of a solution, `Part N` otherwise.
+    pub fn label(&self) -> String {
+        if self.part == PARSE_PART {
+            "Parse".to_string()
+        } else {
+            format!("Part {}", self.part)
+        }
+    }
+
+    /// Returns whether the answer matches the verified one, or [`None`] if the latter is unknown.
+    pub fn correct(&self) -> Option<bool> {
+        self.expected
+            .as_ref()
+            .map(|expected| self.answer.as_ref() == Some(expected))
+    }
+
+    /// Formats the answer along with its check against the verified answer.
+    pub fn checked_answer(&self) -> String {
+        let answer = self.answer.as_deref().unwrap_or("None");
+        match (self.correct(), &self.expected) {
+            (Some(true), _) => format!("{} ✓", answer),
+            (Some(false), Some(expected)) => format!("{} ✗ expected {}", answer, expected),
+            _ if self.answer.is_some() => format!("{} ?", answer),
+            _ => answer.to_string(),
+        }
+    }
+
+    /// Sets the allocations of the first run of a part.
+    pub fn set_allocations(&mut self, allocations: &Allocations) {
+        self.allocations = Some(allocations.count);
+        self.allocated_bytes = Some(allocations.bytes);
+        self.peak_bytes = Some(allocations.peak);
+    }
+
+    /// Formats the allocations of the part, if they were counted.
+    pub fn allocations_str(&self) -> Option<String> {
+        Some(format!(
+            "{} allocs, {}, peak {}",
+            self.allocations?,
+            format_bytes(self.allocated_bytes?),
+            format_bytes(self.peak_bytes?)
+        ))
+    }
+
+    /// Sets the timings of a benchmarked part.
+    pub fn set_stats(&mut self, stats: &Stats) {
+        self.mean = Some(stats.mean);
+        self.min = Some(stats.min);
+        self.samples = stats.samples;
+        self.median = Some(stats.median);
+        self.max = Some(stats.max);
+        self.std_dev = Some(stats.std_dev);
+        self.outliers = Some(stats.outliers);
+    }
+}
+
+/// Serializes durations as a number of nanoseconds.
+pub(super) mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+        match d {
+            Some(d) => s.serialize_some(&(d.as_nanos() as u64)),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(d)?.map(Duration::from_nanos))
+    }
+}
+
+/// Output of the results of solution parts.
+pub trait Reporter {
+    /// Called before the first run of a part.
+    fn part_started(&mut self, _year: u16, _day: Day, _part: u8) {}
+
+    /// Called once the answer of a part is known, before it is benchmarked.
+    fn part_solved(&mut self, _result: &PartResult) {}
+
+    /// Called once a part is done (and benchmarked, in release mode).
+    fn part_finished(&mut self, result: &PartResult);
+
+    /// Called after [`Reporter::part_finished`] with the spans of the instrumented run of a part
+    /// (with `solve --profile`), which took `total`.
+    fn part_profiled(&mut self, _result: &PartResult, _timings: &[SpanTiming], _total: Duration) {}
+
+    /// Called once every part has been reported.
+    fn finish(&mut self) {}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ReportFormat {
+    /// Human readable output
+    #[default]
+    Pretty,
+    /// One JSON object per line
+    Json,
+    /// Comma separated values, with a header line
+    Csv,
+    /// JUnit XML document
+    Junit,
+}
+
+impl ReportFormat {
+    /// Reads the report format from the `AOC_REPORT` environment variable.
+    pub fn from_env() -> Self {
+        env::var(REPORT_ENV_VAR)
+            .ok()
+            .and_then(|format| format.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Creates a reporter, highlighting regressions above the given threshold (in percent).
+    pub fn reporter(self, regression_threshold: f64) -> Box<dyn Reporter> {
+        match self {
+            Self::Pretty => Box::new(PrettyReporter {
+                threshold: regression_threshold,
+                benching: false,
+            }),
+            Self::Json => Box::new(JsonReporter),
+            Self::Csv => Box::new(CsvReporter { header: false }),
+            Self::Junit => Box::new(JunitReporter {
+                results: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl Display for ReportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = clap::ValueEnum::to_possible_value(self).unwrap();
+        f.write_str(value.get_name())
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <Self as clap::ValueEnum>::from_str(s, true)
+    }
+}
+
+/// Returns the reporter selected by the `AOC_REPORT` environment variable.
+pub fn reporter() -> Box<dyn Reporter> {
+    ReportFormat::from_env().reporter(regression_threshold())
+}
+
+fn result_str(result: &PartResult) -> String {
+    match result.part {
+        PARSE_PART => format!("🎄 Day {} {}", result.day, result.label()),
+        _ => format!(
+            "🎄 Day {} {}: {}",
+            result.day,
+            result.label(),
+            result.checked_answer()
+        ),
+    }
+}
+
+/// Emoji-decorated output, rewriting the line of a part once it has been benchmarked.
+///
+/// Benchmarked parts are compared with the last and best recorded runs, regressions above the
+/// threshold (in percent) are highlighted.
+struct PrettyReporter {
+    threshold: f64,
+    /// Whether the line of the current part is to be rewritten.
+    benching: bool,
+}
+
+impl Reporter for PrettyReporter {
+    fn part_solved(&mut self, result: &PartResult) {
+        print!("{} | Benching...", result_str(result));
+        stdout().flush().unwrap();
+        self.benching = true;
+    }
+
+    fn part_finished(&mut self, result: &PartResult) {
+        let result_str = result_str(result);
+        let allocations = result
+            .allocations_str()
+            .map(|allocations| format!(" | {}", allocations))
+            .unwrap_or_default();
+
+        match (&result.error, result.mean) {
+            (Some(error), _) => println!("\x1b[2K\r{} ({})", result_str, error),
+            (None, Some(mean)) if result.samples > 1 => {
+                let mut comparison = String::new();
+                if let Some(last) = result.last {
+                    comparison += &format!(
+                        " | last {}",
+                        format_delta(delta(mean, last), self.threshold)
+                    );
+                }
+                if let Some(best) = result.best {
+                    comparison += &format!(
+                        " | best {}",
+                        format_delta(delta(mean, best), self.threshold)
+                    );
+                }
+                let mut stats = format!("{:.1?}", mean);
+                if let Some(std_dev) = result.std_dev {
+                    stats += &format!(" ± {:.1?}", std_dev);
+                }
+                stats += &format!(" @ {} samples", result.samples);
+                if let (Some(min), Some(median), Some(max)) =
+                    (result.min, result.median, result.max)
+                {
+                    stats += &format!(
+                        " | min {:.1?} | median {:.1?} | max {:.1?}",
+                        min, median, max
+                    );
+                }
+                if let Some(outliers) = result.outliers.filter(|outliers| *outliers > 0) {
+                    stats += &format!(" | {} outliers", outliers);
+                }
+                stats += &allocations;
+                println!("\x1b[2K\r{} ({}){}", result_str, stats, comparison)
+            }
+            // A benchmark can end without samples when the time budget of the part is spent.
+            (None, Some(mean)) if self.benching => {
+                println!("\x1b[2K\r{} ({:.1?}{})", result_str, mean, allocations)
+            }
+            (None, Some(mean)) => println!("{} ({:.1?}{})", result_str, mean, allocations),
+            (None, None) => println!("{}", result_str),
+        }
+        self.benching = false;
+    }
+
+    fn part_profiled(&mut self, _result: &PartResult, timings: &[SpanTiming], total: Duration) {
+        if timings.is_empty() {
+            println!("   No span recorded, add some with `let _span = span!(\"name\");`");
+        }
+        for line in render(timings, total) {
+            println!("   {}", line);
+        }
+    }
+}
+
+struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn part_finished(&mut self, result: &PartResult) {
+        println!("{}", serde_json::to_string(result).unwrap());
+    }
+}
+
+struct CsvReporter {
+    header: bool,
+}
+
+impl Reporter for CsvReporter {
+    fn part_finished(&mut self, result: &PartResult) {
+        if !self.header {
+            println!(
+                "year,day,part,answer,mean_ns,min_ns,samples,median_ns,max_ns,std_dev_ns,outliers,error,last_ns,best_ns,expected,allocations,allocated_bytes,peak_bytes"
+            );
+            self.header = true;
+        }
+
+        let escape = |value: &str| {
+            if value.contains([',', '"', '\n']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        };
+        let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos().to_string()).unwrap_or_default();
+        let count = |n: Option<u64>| n.map(|n| n.to_string()).unwrap_or_default();
+
+        println!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            result.year,
+            u8::from(result.day),
+            result.part,
+            escape(result.answer.as_deref().unwrap_or_default()),
+            nanos(result.mean),
+            nanos(result.min),
+            result.samples,
+            nanos(result.median),
+            nanos(result.max),
+            nanos(result.std_dev),
+            result.outliers.map(|o| o.to_string()).unwrap_or_default(),
+            escape(result.error.as_deref().unwrap_or_default()),
+            nanos(result.last),
+            nanos(result.best),
+            escape(result.expected.as_deref().unwrap_or_default()),
+            count(result.allocations),
+            count(result.allocated_bytes),
+            count(result.peak_bytes),
+        );
+    }
+}
+
+/// Collects every result and prints a JUnit XML document, one test case per part.
+struct JunitReporter {
+    results: Vec<PartResult>,
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+impl Reporter for JunitReporter {
+    fn part_finished(&mut self, result: &PartResult) {
+        self.results.push(result.clone());
+    }
+
+    fn finish(&mut self) {
+        let failures = self
+            .results
+            .iter()
+            .filter(|r| r.error.is_some() || r.correct() == Some(false))
+            .count();
+        let skipped = self
+            .results
+            .iter()
+            .filter(|r| r.error.is_none() && r.answer.is_none() && r.part != PARSE_PART)
+            .count();
+        let total: Duration = self.results.iter().filter_map(|r| r.mean).sum();
+
+        println!(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        println!(
+            r#"<testsuite name="advent_of_code" tests="{}" failures="{}" skipped="{}" time="{:.9}">"#,
+            self.results.len(),
+            failures,
+            skipped,
+            total.as_secs_f64()
+        );
+        for result in &self.results {
+            println!(
+                r#"  <testcase classname="{}.day{}" name="{}" time="{:.9}">"#,
+                result.year,
+                result.day,
+                result.label().to_lowercase(),
+                result.mean.unwrap_or_default().as_secs_f64()
+            );
+            match (&result.error, &result.answer) {
+                (Some(error), _) => {
+                    println!(r#"    <failure message="{}"/>"#, escape_xml(error))
+                }
+                (None, answer) if result.correct() == Some(false) => println!(
+                    r#"    <failure message="expected {}, got {}"/>"#,
+                    escape_xml(result.expected.as_deref().unwrap_or_default()),
+                    escape_xml(answer.as_deref().unwrap_or("None"))
+                ),
+                (None, None) if result.part != PARSE_PART => {
+                    println!(r#"    <skipped message="no answer"/>"#)
+                }
+                (None, answer) => {
+                    if let Some(min) = result.min {
+                        println!("    <properties>");
+                        println!(
+                            r#"      <property name="min_ns" value="{}"/>"#,
+                            min.as_nanos()
+                        );
+                        println!(
+                            r#"      <property name="samples" value="{}"/>"#,
+                            result.samples
+                        );
+                        println!("    </properties>");
+                    }
+                    if let Some(answer) = answer {
+                        println!("    <system-out>{}</system-out>", escape_xml(answer));
+                    }
+                }
+            }
+            println!("  </testcase>");
+        }
+        println!("</testsuite>");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let result = PartResult {
+            answer: Some("143".to_string()),
+            mean: Some(Duration::from_micros(12)),
+            min: Some(Duration::from_nanos(11_500)),
+            samples: 100,
+            ..PartResult::new(2024, crate::day!(5), 1)
+        };
+        let json = serde_json::to_string(&result).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"year":2024,"day":5,"part":1,"answer":"143","mean_ns":12000,"min_ns":11500,"samples":100}"#
+        );
+        assert_eq!(serde_json::from_str::<PartResult>(&json).unwrap(), result);
+    }
+}
@@ -1,65 +1,244 @@
 use std::{
+    env,
     fmt::Display,
     hint::black_box,
-    io::{stdout, Write},
-    time::Instant,
+    process,
+    time::{Duration, Instant},
 };
 
-use super::Day;
+use super::{
+    alloc::{Allocations, Counter},
+    answers::AnswerStore,
+    bench::{pin_to_cpu, BenchConfig, Stats},
+    file::save_last_answer,
+    history,
+    profile::{self, SpanTiming},
+    reporter, Day, InputSource, PartResult, Reporter, PARSE_PART,
+};
+
+/// Minimum number of measured iterations, regardless of the time budget.
+const MIN_SAMPLES: usize = 3;
+
+/// A solution, as registered in the main binary by the build script.
+pub struct Solution {
+    pub year: u16,
+    pub day: Day,
+    /// Runs every part of the solution on an input (see [`solution!`](crate::solution)).
+    pub run: fn(&str, &mut dyn Reporter, &RunOptions),
+}
+
+/// How the parts of a solution are run.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Where the input comes from. Answers are only checked, saved and benchmarks only recorded
+    /// for the puzzle input.
+    pub source: InputSource,
+    /// Benchmark configuration, the parts are only run once without it.
+    pub bench: Option<BenchConfig>,
+    /// Record the [`span!`](crate::span)s of the first run of each part, and report their tree.
+    pub profile: bool,
+    /// Time budget of each part. Benchmarks stop once it is spent, the first run of a part is
+    /// interrupted by the caller (see `solve --timeout`).
+    pub timeout: Option<Duration>,
+    /// Parts not to run, e.g. after one of them timed out.
+    pub skip: Vec<u8>,
+}
 
-/// Run a solution part and print the result to stdout.
+impl RunOptions {
+    fn puzzle_input(&self) -> bool {
+        self.source == InputSource::Input
+    }
+}
+
+/// Run a solution part and report the result.
 ///
-/// In release mode, the solution part will be benchmarked and the result will be printed as well.
-/// The benchmark will be run for 5 seconds or 10k iterations, whichever is the smallest.
-#[allow(dead_code)]
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    #[cfg(debug_assertions)]
-    run_debug(func, input, day, part);
-
-    #[cfg(not(debug_assertions))]
-    run_release(func, input, day, part);
+/// With a benchmark configuration (in release mode), the solution part will be benchmarked and
+/// the result will be reported as well. After a warm-up, the benchmark is run until its time
+/// budget or iteration cap is reached (5 seconds or 10k iterations by default, see
+/// [`BenchConfig`]), and its statistics are appended to the benchmark history of the day.
+///
+/// On the puzzle input, the answer is checked against the answer store, and saved as the last run
+/// of this part so that it can be submitted or recorded afterwards.
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    year: u16,
+    day: Day,
+    part: u8,
+    reporter: &mut dyn Reporter,
+    options: &RunOptions,
+) {
+    if options.skip.contains(&part) {
+        return;
+    }
+    reporter.part_started(year, day, part);
+    let run = first_run(&func, input.clone(), options);
+    let mut result = PartResult {
+        answer: run.output.map(|answer| answer.to_string()),
+        ..PartResult::timed(year, day, part, run.duration)
+    };
+    if let Some(allocations) = &run.allocations {
+        result.set_allocations(allocations);
+    }
+    if options.puzzle_input() {
+        result.expected = AnswerStore::load(year)
+            .ok()
+            .and_then(|answers| answers.get(day, part).map(String::from));
+        save_answer(&result.answer, year, day, part);
+    }
+
+    if let Some(config) = &options.bench {
+        benchmark(&func, input, &mut result, reporter, config, options);
+    }
+
+    reporter.part_finished(&result);
+    if let Some(timings) = &run.timings {
+        reporter.part_profiled(&result, timings, run.duration);
+    }
 }
 
-#[cfg(debug_assertions)]
-fn run_debug<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let result = func(input.clone());
+/// Run the parsing step of a solution and report its timing, like [`run_part`] does.
+///
+/// The parsed input is returned, so that it can be given to each part.
+pub fn run_parse<P>(
+    parse: impl Fn(&str) -> P,
+    input: &str,
+    year: u16,
+    day: Day,
+    reporter: &mut dyn Reporter,
+    options: &RunOptions,
+) -> P {
+    reporter.part_started(year, day, PARSE_PART);
+    let run = first_run(&parse, input, options);
+    let mut result = PartResult::timed(year, day, PARSE_PART, run.duration);
+    if let Some(allocations) = &run.allocations {
+        result.set_allocations(allocations);
+    }
 
-    match result {
-        Some(result) => {
-            println!("🎄 Day {} Part {}: {}", day, part, result);
-        }
-        None => println!("🎄 Day {} Part {}: None", day, part),
+    if let Some(config) = &options.bench {
+        benchmark(&parse, input, &mut result, reporter, config, options);
     }
+
+    reporter.part_finished(&result);
+    if let Some(timings) = &run.timings {
+        reporter.part_profiled(&result, timings, run.duration);
+    }
+    run.output
+}
+
+/// Entry point of the solution binaries: reads the input selected by the command line arguments
+/// (`--example <N>` or `--input <path|->`, the puzzle input by default) and runs every part.
+///
+/// The parts are benchmarked in release mode.
+pub fn run_main(year: u16, day: Day, run: fn(&str, &mut dyn Reporter, &RunOptions)) {
+    let source = InputSource::from_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("🫎 {}", e);
+        process::exit(1);
+    });
+    let input = source.read(year, day).unwrap_or_else(|e| {
+        eprintln!("🫎 Could not open input file: {}", e);
+        process::exit(1);
+    });
+
+    let options = RunOptions {
+        source,
+        bench: (!cfg!(debug_assertions)).then(BenchConfig::from_env),
+        ..RunOptions::default()
+    };
+    let mut reporter = reporter();
+    run(&input, reporter.as_mut(), &options);
+    reporter.finish();
 }
 
-#[allow(dead_code)]
-fn run_release<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let cloned = input.clone();
+fn time<I, O>(func: impl Fn(I) -> O, input: I) -> (O, Duration) {
     let start = Instant::now();
-    let result = func(cloned);
-    let base_time = start.elapsed();
+    let output = func(input);
+    (output, start.elapsed())
+}
+
+/// Measurements of the first run of a part.
+struct FirstRun<O> {
+    output: O,
+    duration: Duration,
+    /// Spans of the run, when profiling.
+    timings: Option<Vec<SpanTiming>>,
+    /// Allocations of the run, with the `count-alloc` feature.
+    allocations: Option<Allocations>,
+}
+
+/// Times a run, recording its spans when profiling and counting its allocations.
+fn first_run<I, O>(func: impl Fn(I) -> O, input: I, options: &RunOptions) -> FirstRun<O> {
+    if options.profile {
+        profile::start();
+    }
+    let counter = Counter::start();
+    let (output, duration) = time(func, input);
+    let allocations = counter.map(Counter::stop);
+    FirstRun {
+        output,
+        duration,
+        timings: options.profile.then(profile::finish),
+        allocations,
+    }
+}
 
-    let result_str = match result {
-        Some(result) => {
-            format!("🎄 Day {} Part {}: {}", day, part, result)
+fn save_answer(answer: &Option<String>, year: u16, day: Day, part: u8) {
+    if let Some(answer) = answer {
+        if let Err(e) = save_last_answer(year, day, part, answer) {
+            eprintln!("🫎 Failed to save answer: {}", e);
         }
-        None => format!("🎄 Day {} Part {}: None", day, part),
-    };
-    print!("{} | Benching...", result_str);
-    stdout().flush().unwrap();
+    }
+}
+
+fn benchmark<I: Clone, O>(
+    func: impl Fn(I) -> O,
+    input: I,
+    result: &mut PartResult,
+    reporter: &mut dyn Reporter,
+    config: &BenchConfig,
+    options: &RunOptions,
+) {
+    // The thread is only pinned while benchmarking, later runs use every CPU again.
+    let _pin = config.cpu.and_then(|cpu| {
+        pin_to_cpu(cpu)
+            .map_err(|e| eprintln!("🫎 Failed to pin benchmark to CPU {}: {}", cpu, e))
+            .ok()
+    });
+    reporter.part_solved(result);
+
+    // The first run of the part already used some of its time budget.
+    let deadline = options.timeout.and_then(|timeout| {
+        Instant::now().checked_add(timeout.saturating_sub(result.mean.unwrap_or_default()))
+    });
+    let expired = || deadline.is_some_and(|deadline| Instant::now() >= deadline);
+
+    // Warm up caches and branch predictors before measuring.
+    let start = Instant::now();
+    for _ in 0..config.max_iterations {
+        if start.elapsed() >= config.warmup || expired() {
+            break;
+        }
+        black_box(func(black_box(input.clone())));
+    }
 
-    let nb_iter = (5_000_000_000 / base_time.as_nanos() as usize).clamp(3, 10000);
-    let mut total_time = 0;
-    for _ in 0..nb_iter {
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.len() < config.max_iterations
+        && (samples.len() < MIN_SAMPLES || start.elapsed() < config.time_budget)
+        && !expired()
+    {
         let cloned = input.clone();
-        let start = Instant::now();
+        let sample_start = Instant::now();
         black_box(func(black_box(cloned)));
-        total_time += start.elapsed().as_nanos() as usize;
+        samples.push(sample_start.elapsed());
     }
 
-    let duration = std::time::Duration::from_nanos((total_time / nb_iter) as u64);
-    println!(
-        "\x1b[2K\r{} ({:.1?} @ {} samples)",
-        result_str, duration, nb_iter
-    );
+    if let Some(stats) = Stats::from_samples(&mut samples) {
+        result.set_stats(&stats);
+    }
+    if options.puzzle_input() {
+        if let Err(e) = history::record(result) {
+            eprintln!("🫎 Failed to record benchmark: {}", e);
+        }
+    }
 }
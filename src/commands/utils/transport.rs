@@ -0,0 +1,175 @@
+use std::{
+    env,
+    fs::{create_dir_all, metadata, read_to_string, remove_file, write},
+    io,
+    path::PathBuf,
+    process, thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_client::{AocError, AocResult};
+use reqwest::{
+    blocking::{Client, RequestBuilder},
+    header::{HeaderMap, HeaderValue, COOKIE, USER_AGENT},
+    redirect::Policy,
+};
+
+use super::{checksum::sha256, data_dir};
+
+/// Environment variable overriding the base URL of adventofcode.com, e.g. to use a local server.
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+/// Environment variable holding the minimum time between two requests, in seconds.
+pub const THROTTLE_ENV_VAR: &str = "AOC_THROTTLE";
+/// Environment variable overriding the User-Agent, to add contact information to it.
+pub const USER_AGENT_ENV_VAR: &str = "AOC_USER_AGENT";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_THROTTLE: Duration = Duration::from_secs(5);
+const DEFAULT_USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " by ",
+    env!("CARGO_PKG_AUTHORS")
+);
+
+/// Reads the throttle delay from the environment, exiting if it is not a valid duration.
+fn throttle_from_env() -> Duration {
+    let Ok(value) = env::var(THROTTLE_ENV_VAR) else {
+        return DEFAULT_THROTTLE;
+    };
+    match value.parse().map(Duration::try_from_secs_f64) {
+        Ok(Ok(throttle)) => throttle,
+        _ => {
+            eprintln!(
+                "🫎 Invalid {} `{}`, expecting a non-negative number of seconds",
+                THROTTLE_ENV_VAR, value
+            );
+            process::exit(1);
+        }
+    }
+}
+
+/// The HTTP layer every request to adventofcode.com goes through.
+///
+/// Requests are spaced by at least the throttle delay, even across runs (the time of the last
+/// request is kept in `data/cache/`), and `GET` responses are cached in `data/cache/`, apart for
+/// each session cookie.
+pub struct Transport {
+    client: Client,
+    base_url: String,
+    throttle: Duration,
+    cache_dir: PathBuf,
+}
+
+impl Transport {
+    pub fn new(session_cookie: &str) -> AocResult<Self> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            COOKIE,
+            HeaderValue::from_str(&format!("session={}", session_cookie))
+                .map_err(|_| AocError::InvalidSessionCookie)?,
+        );
+        let user_agent = env::var(USER_AGENT_ENV_VAR).unwrap_or(DEFAULT_USER_AGENT.to_string());
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(&user_agent)
+                .unwrap_or(HeaderValue::from_static(DEFAULT_USER_AGENT)),
+        );
+
+        Ok(Self {
+            client: Client::builder()
+                .default_headers(headers)
+                .redirect(Policy::none())
+                .build()?,
+            base_url: env::var(BASE_URL_ENV_VAR)
+                .unwrap_or(DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            throttle: throttle_from_env(),
+            // The cookie itself is not written to disk, only a prefix of its hash.
+            cache_dir: data_dir("cache").join(&sha256(session_cookie.as_bytes())[..16]),
+        })
+    }
+
+    /// Fetches a page, from the cache if it was fetched less than `max_age` ago (unless `refresh`
+    /// is set).
+    pub fn get(&self, path: &str, max_age: Duration, refresh: bool) -> AocResult<String> {
+        let cache = self.cache_path(path);
+        let age = metadata(&cache)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok());
+        if !refresh && age.is_some_and(|age| age < max_age) {
+            if let Ok(content) = read_to_string(&cache) {
+                return Ok(content);
+            }
+        }
+
+        let content = self.send(self.client.get(self.url(path)))?;
+        let saved = match cache.parent() {
+            Some(parent) => create_dir_all(parent).and_then(|_| write(&cache, &content)),
+            None => write(&cache, &content),
+        };
+        if let Err(e) = saved {
+            eprintln!("🫎 Failed to cache response: {}", e);
+        }
+        Ok(content)
+    }
+
+    /// Submits a form, the response is never cached.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> AocResult<String> {
+        self.send(self.client.post(self.url(path)).form(form))
+    }
+
+    /// Removes a page from the cache, for the next [`Transport::get`] to fetch it again.
+    pub fn invalidate(&self, path: &str) {
+        match remove_file(self.cache_path(path)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => {
+                eprintln!("🫎 Failed to invalidate cached response: {}", e);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the path of the cached response of a page.
+    fn cache_path(&self, path: &str) -> PathBuf {
+        self.cache_dir
+            .join(path.trim_matches('/').replace('/', "-"))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn send(&self, request: RequestBuilder) -> AocResult<String> {
+        self.wait_turn();
+        let response = request.send()?.error_for_status()?;
+        // Redirections are not followed, adventofcode.com redirects pages that are not available.
+        if response.status().is_redirection() {
+            return Err(AocError::AocResponseError);
+        }
+        Ok(response.text()?)
+    }
+
+    /// Sleeps until the throttle delay has passed since the last request, then marks the current
+    /// time as the time of the last request.
+    fn wait_turn(&self) {
+        let last_request = data_dir("cache").join("last_request");
+        let elapsed = read_to_string(&last_request)
+            .ok()
+            .and_then(|time| time.trim().parse().ok())
+            .map(|time| UNIX_EPOCH + Duration::from_secs_f64(time))
+            .and_then(|time| SystemTime::now().duration_since(time).ok());
+        if let Some(elapsed) = elapsed {
+            thread::sleep(self.throttle.saturating_sub(elapsed));
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let marked = create_dir_all(data_dir("cache"))
+            .and_then(|_| write(&last_request, now.as_secs_f64().to_string()));
+        if let Err(e) = marked {
+            eprintln!("🫎 Failed to record request time: {}", e);
+        }
+    }
+}
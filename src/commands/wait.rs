@@ -0,0 +1,228 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    process, thread,
+    time::Duration,
+};
+
+use aoc_client::AocError;
+use chrono::{DateTime, Utc};
+
+use super::aoc::{get_input, unlock_time};
+use super::{scaffold, Day, Event};
+
+/// Number of attempts to download the input once the puzzle is unlocked.
+const MAX_ATTEMPTS: u32 = 8;
+const FIRST_RETRY_DELAY: Duration = Duration::from_secs(2);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// The source of time of the countdown and the retries, replaced by a fake one in tests.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Returns the first day of the event that is not unlocked yet.
+fn next_day(event: Event, now: DateTime<Utc>) -> Option<Day> {
+    event
+        .days()
+        .find(|day| unlock_time(event.year(), *day) > now)
+}
+
+fn format_remaining(seconds: u64) -> String {
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    match seconds / 86400 {
+        0 => time,
+        days => format!("{}d {}", days, time),
+    }
+}
+
+/// Shows a countdown until the unlock instant, updated every second.
+fn countdown(
+    day: Day,
+    unlock: DateTime<Utc>,
+    clock: &dyn Clock,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    while let Ok(remaining) = (unlock - clock.now()).to_std() {
+        if remaining.is_zero() {
+            break;
+        }
+        let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+        write!(
+            out,
+            "\r🎄 Day {} unlocks in {}\x1b[K",
+            day,
+            format_remaining(seconds)
+        )?;
+        out.flush()?;
+
+        // Wake up on the next whole second of the countdown.
+        match remaining.subsec_nanos() {
+            0 => clock.sleep(Duration::from_secs(1)),
+            nanos => clock.sleep(Duration::from_nanos(nanos.into())),
+        }
+    }
+    writeln!(out, "\r🎄 Day {} is unlocked!\x1b[K", day)
+}
+
+/// Calls `attempt` until it succeeds, an error is not `retryable`, or [`MAX_ATTEMPTS`] have failed,
+/// doubling the delay between two attempts.
+fn retry<T, E: Display>(
+    clock: &dyn Clock,
+    mut attempt: impl FnMut() -> Result<T, E>,
+    retryable: impl Fn(&E) -> bool,
+) -> Result<T, E> {
+    let mut delay = FIRST_RETRY_DELAY;
+    for _ in 1..MAX_ATTEMPTS {
+        match attempt() {
+            Err(e) if retryable(&e) => {
+                eprintln!(
+                    "🫎 Input not available yet ({}), retrying in {:?}",
+                    e, delay
+                );
+                clock.sleep(delay);
+                delay = (delay * 2).min(MAX_RETRY_DELAY);
+            }
+            result => return result,
+        }
+    }
+    attempt()
+}
+
+/// Waits for the puzzle of a day (by default the next one to unlock) to be unlocked, then
+/// scaffolds it and downloads its input.
+pub fn handle(event: Event, day: Option<Day>, clock: &dyn Clock) {
+    let year = event.year();
+    let day = match day {
+        Some(day) => event.day_safe(Some(day)),
+        None => next_day(event, clock.now()).unwrap_or_else(|| {
+            eprintln!("🫎 Every puzzle of {} is already unlocked", year);
+            process::exit(1);
+        }),
+    };
+
+    if let Err(e) = countdown(day, unlock_time(year, day), clock, &mut io::stdout()) {
+        eprintln!("🫎 Failed to show countdown: {}", e);
+    }
+
+    // The input is cached by the transport, for the scaffold to pick it up.
+    let downloaded = retry(
+        clock,
+        || get_input(year, day, false),
+        |e| {
+            !matches!(
+                e,
+                AocError::SessionFileNotFound
+                    | AocError::SessionFileReadError { .. }
+                    | AocError::InvalidSessionCookie
+            )
+        },
+    );
+    if let Err(e) = downloaded {
+        eprintln!("🫎 Failed to download input: {}", e);
+        process::exit(1);
+    }
+
+    let options = scaffold::Options {
+        download: true,
+        ..Default::default()
+    };
+    scaffold::handle(year, day, &options);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    /// A clock whose time only moves when sleeping.
+    struct FakeClock {
+        now: Cell<DateTime<Utc>>,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl FakeClock {
+        fn new(now: DateTime<Utc>) -> Self {
+            Self {
+                now: Cell::new(now),
+                sleeps: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+            self.sleeps.borrow_mut().push(duration);
+        }
+    }
+
+    #[test]
+    fn test_countdown() {
+        let day = Day::new(5).unwrap();
+        let unlock = unlock_time(2024, day);
+        assert_eq!(unlock, Utc.with_ymd_and_hms(2024, 12, 5, 5, 0, 0).unwrap());
+
+        let clock = FakeClock::new(unlock - Duration::from_millis(2500));
+        assert_eq!(next_day(Event::new(2024).unwrap(), clock.now()), Some(day));
+        let mut out = Vec::new();
+        countdown(day, unlock, &clock, &mut out).unwrap();
+
+        assert_eq!(clock.now(), unlock);
+        assert_eq!(
+            *clock.sleeps.borrow(),
+            [500, 1000, 1000].map(Duration::from_millis)
+        );
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("\r🎄 Day 05 unlocks in 00:00:03\x1b[K"));
+        assert!(out.ends_with("\r🎄 Day 05 is unlocked!\x1b[K\n"));
+        assert_eq!(format_remaining(90061), "1d 01:01:01");
+    }
+
+    #[test]
+    fn test_retry() {
+        let clock = FakeClock::new(Utc::now());
+        let mut attempts = 0;
+        let result = retry(
+            &clock,
+            || {
+                attempts += 1;
+                if attempts < 4 {
+                    Err("404")
+                } else {
+                    Ok(attempts)
+                }
+            },
+            |_| true,
+        );
+
+        assert_eq!(result, Ok(4));
+        assert_eq!(*clock.sleeps.borrow(), [2, 4, 8].map(Duration::from_secs));
+        assert_eq!(retry(&clock, || Err::<(), _>("401"), |_| false), Err("401"));
+    }
+}
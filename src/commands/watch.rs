@@ -0,0 +1,216 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::{read_dir, read_to_string},
+    path::{Path, PathBuf},
+    process::{self, Command},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use chrono::Local;
+use regex::Regex;
+
+use super::{data_path, solve, status::function_body, Day, PartResult};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Editors write several files (or the same one several times) when saving, the run starts once
+/// nothing changed for this long.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Modification times of the watched files.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+fn collect(path: &Path, filter: &dyn Fn(&Path) -> bool, snapshot: &mut Snapshot) {
+    let Ok(entries) = read_dir(path) else {
+        return;
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        if path.is_dir() {
+            collect(&path, filter, snapshot);
+        } else if filter(&path) {
+            if let Ok(modified) = entry.metadata().and_then(|metadata| metadata.modified()) {
+                snapshot.insert(path, modified);
+            }
+        }
+    }
+}
+
+/// Reads the modification times of the module of a day, the library, and the examples and input
+/// of the day.
+fn snapshot(year: u16, day: Day) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let bin = Path::new("src/bin");
+    collect(
+        Path::new("src"),
+        &|path| !path.starts_with(bin),
+        &mut snapshot,
+    );
+    collect(
+        &bin.join(format!("{}-{}", year, day)),
+        &|_| true,
+        &mut snapshot,
+    );
+    collect(
+        &data_path("examples", year, ""),
+        &|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with(&format!("{}-", day)))
+        },
+        &mut snapshot,
+    );
+    collect(
+        &data_path("inputs", year, ""),
+        &|path| path.ends_with(format!("{}.txt", day)),
+        &mut snapshot,
+    );
+    snapshot
+}
+
+/// Returns the example and the expected answer of each part whose example test asserts a known
+/// answer, as in the template: `part_one(&read_example(YEAR, DAY, 1))` and
+/// `assert_eq!(result, Some(42))`.
+fn example_checks(source: &str) -> Vec<(u8, u8, String)> {
+    let example = Regex::new(r"read_example\(YEAR, DAY, (\d+)\)").unwrap();
+    let expected = Regex::new(r"assert_eq!\(result, Some\((.+)\)\)").unwrap();
+
+    [("one", 1), ("two", 2)]
+        .into_iter()
+        .filter_map(|(name, part)| {
+            let body = function_body(source, &format!("example_part_{}", name))?;
+            let example = example.captures(body)?[1].parse().ok()?;
+            let expected = expected.captures(body)?[1]
+                .trim_end_matches("u64")
+                .to_string();
+            Some((part, example, expected))
+        })
+        .collect()
+}
+
+fn cargo(release: bool, command: &str) -> Command {
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or("cargo".to_string()));
+    cargo.args([command, "--quiet", "--bin", "advent_of_code"]);
+    if release {
+        cargo.arg("--release");
+    }
+    cargo
+}
+
+/// Rebuilds the binary, runs the day with the options of the watch command, then checks the
+/// answers on the examples.
+fn run(year: u16, day: Day, args: &[String], release: bool) {
+    print!("\x1b[2J\x1b[H");
+    println!(
+        "🎄 Watching day {} of {} ({}), Ctrl-C to stop",
+        day,
+        year,
+        Local::now().format("%H:%M:%S")
+    );
+    println!();
+
+    if !cargo(release, "build").status().is_ok_and(|s| s.success()) {
+        eprintln!("🫎 Build failed, waiting for changes");
+        return;
+    }
+    let _ = cargo(release, "run").arg("--").args(args).status();
+
+    let source = read_to_string(format!("src/bin/{}-{}/main.rs", year, day)).unwrap_or_default();
+    let checks = example_checks(&source);
+    if !checks.is_empty() {
+        println!();
+    }
+
+    // Each example is solved once, for the answers of both parts.
+    let mut results: BTreeMap<u8, Vec<PartResult>> = BTreeMap::new();
+    for (part, example, expected) in checks {
+        let results = results.entry(example).or_insert_with(|| {
+            let output = cargo(release, "run")
+                .args(["--", &year.to_string(), "solve", &day.to_string()])
+                .args(["--example", &example.to_string(), "--report", "json"])
+                .output();
+            output
+                .map(|output| {
+                    String::from_utf8_lossy(&output.stdout)
+                        .lines()
+                        .filter_map(|line| serde_json::from_str(line).ok())
+                        .collect()
+                })
+                .unwrap_or_default()
+        });
+        let answer = results
+            .iter()
+            .find(|result| result.part == part)
+            .and_then(|result| result.answer.clone());
+        match answer {
+            Some(answer) if answer == expected => {
+                println!("🎄 Example {} part {}: {} ✓", example, part, answer)
+            }
+            Some(answer) => println!(
+                "🫎 Example {} part {}: {} ✗ expected {}",
+                example, part, answer, expected
+            ),
+            None => println!("🫎 Example {} part {}: no answer", example, part),
+        }
+    }
+}
+
+/// Solves a day again every time its module, the library, or its examples and input change.
+///
+/// The binary is rebuilt and run in a child process with the same options, without `--watch`.
+/// Files are polled, so that it works on any filesystem.
+pub fn handle(year: u16, day: Day, options: &solve::Options) {
+    if !Path::new("src/bin").exists() {
+        eprintln!("🫎 No `src/bin` directory, run `solve --watch` from the root of the repo");
+        process::exit(1);
+    }
+
+    let args = options.args(year, day);
+    let release = !cfg!(debug_assertions);
+
+    let mut last = snapshot(year, day);
+    run(year, day, &args, release);
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let mut current = snapshot(year, day);
+        if current == last {
+            continue;
+        }
+
+        // Wait for the burst of changes to settle.
+        loop {
+            thread::sleep(DEBOUNCE);
+            let settled = snapshot(year, day);
+            if settled == current {
+                break;
+            }
+            current = settled;
+        }
+        last = current;
+        run(year, day, &args, release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_checks() {
+        let source = r#"
+    #[test]
+    fn example_part_one() {
+        let result = part_one(&parse(&read_example(YEAR, DAY, 2)));
+        assert_eq!(result, Some(3749));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let result = part_two(&read_example(YEAR, DAY, 1));
+        assert_eq!(result, None);
+    }
+"#;
+
+        assert_eq!(example_checks(source), [(1, 2, "3749".to_string())]);
+    }
+}
@@ -1,9 +1,11 @@
 mod coordinate;
 mod coordinate3d;
+mod render;
 mod sparse;
 
 pub use coordinate::Coordinate;
 pub use coordinate3d::Coordinate3D;
+pub use render::{Animation, Canvas, Color, Style};
 pub use sparse::SparseGrid;
 
 /// Cardinal directions
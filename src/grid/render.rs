@@ -0,0 +1,302 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Write as _},
+    io::{stdout, IsTerminal, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use num::ToPrimitive;
+
+use super::{Coordinate, SparseGrid};
+
+/// ANSI colours
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// Offset of the colour in the ANSI foreground (30-37) and background (40-47) codes
+    fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+/// How a cell is drawn: a character, its colours and weight
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub symbol: char,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    /// Plain character, without colours
+    pub fn new(symbol: char) -> Self {
+        Self {
+            symbol,
+            fg: None,
+            bg: None,
+            bold: false,
+        }
+    }
+
+    pub fn fg(self, color: Color) -> Self {
+        Self {
+            fg: Some(color),
+            ..self
+        }
+    }
+
+    pub fn bg(self, color: Color) -> Self {
+        Self {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    pub fn bold(self) -> Self {
+        Self { bold: true, ..self }
+    }
+
+    fn write(&self, out: &mut String) {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if let Some(fg) = self.fg {
+            codes.push((30 + fg.code()).to_string());
+        }
+        if let Some(bg) = self.bg {
+            codes.push((40 + bg.code()).to_string());
+        }
+
+        if codes.is_empty() {
+            out.push(self.symbol);
+        } else {
+            write!(out, "\x1b[{}m{}\x1b[0m", codes.join(";"), self.symbol).unwrap();
+        }
+    }
+}
+
+impl From<char> for Style {
+    fn from(symbol: char) -> Self {
+        Self::new(symbol)
+    }
+}
+
+fn to_i64<T: ToPrimitive>(coordinate: &Coordinate<T>) -> Coordinate<i64> {
+    Coordinate {
+        x: coordinate.x.to_i64().unwrap(),
+        y: coordinate.y.to_i64().unwrap(),
+    }
+}
+
+/// A grid of styled cells, drawn in the terminal
+///
+/// Cells outside of the grid are ignored, cells of the grid without a style are drawn as the
+/// empty style (`.` by default).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canvas {
+    min: Coordinate<i64>,
+    max: Coordinate<i64>,
+    cells: HashMap<Coordinate<i64>, Style>,
+    empty: Style,
+}
+
+impl Canvas {
+    /// Empty canvas, from `min` to `max` included
+    pub fn new<T: ToPrimitive>(min: Coordinate<T>, max: Coordinate<T>) -> Self {
+        Self {
+            min: to_i64(&min),
+            max: to_i64(&max),
+            cells: HashMap::new(),
+            empty: Style::new('.'),
+        }
+    }
+
+    /// Canvas of a dense grid, fitting its coordinates
+    pub fn from_grid<T, V>(grid: &HashMap<Coordinate<T>, V>, style: impl Fn(&V) -> Style) -> Self
+    where
+        T: ToPrimitive,
+    {
+        let coordinates: Vec<Coordinate<i64>> = grid.keys().map(to_i64).collect();
+        let min = Coordinate {
+            x: coordinates.iter().map(|c| c.x).min().unwrap_or_default(),
+            y: coordinates.iter().map(|c| c.y).min().unwrap_or_default(),
+        };
+        let max = Coordinate {
+            x: coordinates.iter().map(|c| c.x).max().unwrap_or(-1),
+            y: coordinates.iter().map(|c| c.y).max().unwrap_or(-1),
+        };
+
+        let mut canvas = Self::new(min, max);
+        for (coordinate, value) in grid {
+            canvas.cells.insert(to_i64(coordinate), style(value));
+        }
+        canvas
+    }
+
+    /// Canvas of a sparse grid, covering its whole width and height
+    pub fn from_sparse<T, V>(grid: &SparseGrid<T, V>, style: impl Fn(&V) -> Style) -> Self
+    where
+        T: ToPrimitive,
+    {
+        let max = Coordinate {
+            x: grid.width.to_i64().unwrap() - 1,
+            y: grid.height.to_i64().unwrap() - 1,
+        };
+
+        let mut canvas = Self::new(Coordinate { x: 0, y: 0 }, max);
+        for (coordinate, value) in &grid.objects {
+            canvas.cells.insert(to_i64(coordinate), style(value));
+        }
+        canvas
+    }
+
+    /// Style of the cells without one
+    pub fn empty(mut self, style: impl Into<Style>) -> Self {
+        self.empty = style.into();
+        self
+    }
+
+    /// Draws a marker on a cell
+    pub fn mark<T: ToPrimitive>(&mut self, coordinate: Coordinate<T>, style: impl Into<Style>) {
+        self.cells.insert(to_i64(&coordinate), style.into());
+    }
+
+    /// Highlights the background of cells, e.g. a path, keeping their character
+    pub fn highlight<T: ToPrimitive>(
+        &mut self,
+        coordinates: impl IntoIterator<Item = Coordinate<T>>,
+        color: Color,
+    ) {
+        for coordinate in coordinates {
+            let coordinate = to_i64(&coordinate);
+            let style = self.cells.get(&coordinate).copied().unwrap_or(self.empty);
+            self.cells.insert(coordinate, style.bg(color));
+        }
+    }
+
+    /// Prints the canvas, only if the standard output is a terminal
+    pub fn print(&self) {
+        if stdout().is_terminal() {
+            println!("{}", self);
+        }
+    }
+}
+
+impl Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = String::new();
+        for y in self.min.y..=self.max.y {
+            if y > self.min.y {
+                out.push('\n');
+            }
+            for x in self.min.x..=self.max.x {
+                self.cells
+                    .get(&Coordinate { x, y })
+                    .unwrap_or(&self.empty)
+                    .write(&mut out);
+            }
+        }
+        f.write_str(&out)
+    }
+}
+
+/// Plays canvases as the frames of an animation, redrawn in place
+///
+/// Nothing is drawn (and frames are not even built) when the standard output is not a terminal,
+/// so that animations can be left in solutions.
+pub struct Animation {
+    delay: Duration,
+    enabled: bool,
+    last_frame: Option<Instant>,
+}
+
+impl Animation {
+    /// Animation at the given number of frames per second
+    ///
+    /// Panics if the frame rate is not positive.
+    pub fn new(frame_rate: f64) -> Self {
+        let delay = Duration::try_from_secs_f64(1.0 / frame_rate).unwrap_or_else(|_| {
+            panic!(
+                "🫎 Invalid frame rate `{}`, expecting a positive number of frames per second",
+                frame_rate
+            )
+        });
+        Self {
+            delay,
+            enabled: stdout().is_terminal(),
+            last_frame: None,
+        }
+    }
+
+    /// Draws a frame, waiting for the previous one to have been shown long enough
+    pub fn frame(&mut self, draw: impl FnOnce() -> Canvas) {
+        if !self.enabled {
+            return;
+        }
+        let canvas = draw();
+        if let Some(elapsed) = self.last_frame.map(|last| last.elapsed()) {
+            thread::sleep(self.delay.saturating_sub(elapsed));
+        }
+
+        let mut stdout = stdout().lock();
+        // Clear the screen and move the cursor to the top left corner.
+        write!(stdout, "\x1b[2J\x1b[H{}", canvas).unwrap();
+        stdout.flush().unwrap();
+        self.last_frame = Some(Instant::now());
+    }
+
+    /// Plays every frame
+    pub fn play(&mut self, frames: impl IntoIterator<Item = Canvas>) {
+        if !self.enabled {
+            return;
+        }
+        for canvas in frames {
+            self.frame(|| canvas);
+        }
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canvas() {
+        let grid: HashMap<Coordinate<i32>, char> = [((0, 0), '#'), ((2, 1), '#')]
+            .into_iter()
+            .map(|((x, y), c)| (Coordinate { x, y }, c))
+            .collect();
+        let mut canvas = Canvas::from_grid(&grid, |c| Style::new(*c)).empty(' ');
+        assert_eq!(canvas.to_string(), "#  \n  #");
+
+        canvas.mark(
+            Coordinate { x: 1, y: 0 },
+            Style::new('S').fg(Color::Red).bold(),
+        );
+        canvas.highlight([Coordinate { x: 1, y: 1 }], Color::Blue);
+        assert_eq!(
+            canvas.to_string(),
+            "#\x1b[1;31mS\x1b[0m \n \x1b[44m \x1b[0m#"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid frame rate")]
+    fn test_animation_frame_rate() {
+        Animation::new(0.0);
+    }
+}
@@ -1,34 +1,109 @@
 mod args;
+mod registry;
 
-use advent_of_code::commands::{download, scaffold, solve, utils::Day};
+use std::time::Duration;
+
+use advent_of_code::commands::{
+    bench::BenchConfig, download, leaderboard, read, record, scaffold, solve, status, submit, test,
+    wait, watch, Event, InputSource,
+};
 use args::{Arguments, Command};
-use chrono::Datelike;
 use clap::Parser;
 
 fn main() {
     let args = Arguments::parse();
 
-    let year = match args.year {
-        Some(year) => year,
-        None => {
-            let now = chrono::Utc::now();
-            if now.month() == 12 {
-                now.year()
+    let event = args.year.unwrap_or_else(Event::latest);
+    let year = event.year();
+
+    match &args.command {
+        Command::Scaffold {
+            day,
+            download,
+            template,
+            dry_run,
+            force,
+        } => {
+            let options = scaffold::Options {
+                download: *download,
+                template: template.clone(),
+                dry_run: *dry_run,
+                force: *force,
+            };
+            scaffold::handle(year, event.day_safe(*day), &options)
+        }
+        Command::Solve {
+            days,
+            all,
+            release,
+            example,
+            input,
+            report,
+            regression_threshold,
+            warmup,
+            bench_time,
+            bench_iterations,
+            pin,
+            timeout,
+            profile,
+            watch,
+            events,
+            skip,
+        } => {
+            let options = solve::Options {
+                release: *release,
+                report: *report,
+                regression_threshold: *regression_threshold,
+                bench: BenchConfig {
+                    warmup: Duration::from_secs_f64(*warmup),
+                    time_budget: Duration::from_secs_f64(*bench_time),
+                    max_iterations: *bench_iterations,
+                    cpu: *pin,
+                },
+                source: InputSource::new(*example, input.clone()),
+                timeout: *timeout,
+                profile: *profile,
+            };
+            if *events {
+                let day = event.day_safe(days.map(|days| days.first));
+                solve::handle_events(year, day, &options, skip, registry::SOLUTIONS)
+            } else if *watch {
+                if days.is_some_and(|days| days.single().is_none()) {
+                    eprintln!("🫎 Only a single day can be watched");
+                    std::process::exit(1);
+                }
+                watch::handle(year, event.day_safe(days.map(|days| days.first)), &options)
+            } else if *all || days.is_some_and(|days| days.single().is_none()) {
+                solve::handle_all(event, *days, &options, registry::SOLUTIONS)
             } else {
-                now.year() - 1
+                let day = event.day_safe(days.map(|days| days.first));
+                solve::handle(year, day, &options, registry::SOLUTIONS)
             }
         }
-        .try_into()
-        .unwrap(),
-    };
-
-    match &args.command {
-        Command::Scaffold { day, download } => {
-            scaffold::handle(year, day.unwrap_or_else(Day::today_safe), *download)
+        Command::Test {
+            day,
+            all,
+            examples_only,
+            inputs_only,
+        } => {
+            let day = (!*all).then(|| event.day_safe(*day));
+            let filter = match (examples_only, inputs_only) {
+                (true, _) => test::Filter::ExamplesOnly,
+                (_, true) => test::Filter::InputsOnly,
+                _ => test::Filter::All,
+            };
+            test::handle(event, day, filter)
+        }
+        Command::Read { day, refresh } => read::handle(year, event.day_safe(*day), *refresh),
+        Command::Status => status::handle(event),
+        Command::Leaderboard { id } => leaderboard::handle(event, *id),
+        Command::Wait { day } => wait::handle(event, *day, &wait::SystemClock),
+        Command::Download { day, force } => download::handle(year, event.day_safe(*day), *force),
+        Command::Submit { day, part, answer } => {
+            submit::handle(year, event.day_safe(Some(*day)), *part, answer.clone())
         }
-        Command::Solve { day, release } => {
-            solve::handle(year, day.unwrap_or_else(Day::today_safe), *release)
+        Command::Record { day, part, force } => {
+            record::handle(year, event.day_safe(*day), *part, *force)
         }
-        Command::Download { day } => download::handle(year, day.unwrap_or_else(Day::today_safe)),
     }
 }
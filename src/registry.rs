@@ -0,0 +1,9 @@
+//! Registry of the solutions in `src/bin`, generated by the build script.
+
+// The solutions are left out of the tests of the main binary, as they are already tested through
+// their own binaries.
+#[cfg(not(test))]
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+#[cfg(test)]
+pub const SOLUTIONS: &[advent_of_code::commands::Solution] = &[];
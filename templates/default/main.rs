@@ -1,3 +1,5 @@
+// Day %DAY%: %TITLE% (input: %INPUT_SHAPE%)
+
 advent_of_code::solution!(%YEAR%, %DAY%);
 
 pub fn part_one(_input: &str) -> Option<u32> {
@@ -11,29 +13,29 @@ pub fn part_two(_input: &str) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use advent_of_code::commands::{read_example, read_input};
+    use advent_of_code::commands::{read_answer, read_example, read_input};
 
     #[test]
     fn example_part_one() {
-        let result = part_one(&read_example(YEAR, DAY, 1));
-        assert_eq!(result, None);
+        let result = part_one(&read_example(YEAR, DAY, %EXAMPLE_1%));
+        assert_eq!(result, %ANSWER_1%);
     }
 
     #[test]
     fn input_part_one() {
         let result = part_one(&read_input(YEAR, DAY));
-        assert_eq!(result, None);
+        assert_eq!(result, read_answer(YEAR, DAY, 1));
     }
 
     #[test]
     fn example_part_two() {
-        let result = part_two(&read_example(YEAR, DAY, 1));
-        assert_eq!(result, None);
+        let result = part_two(&read_example(YEAR, DAY, %EXAMPLE_2%));
+        assert_eq!(result, %ANSWER_2%);
     }
 
     #[test]
     fn input_part_two() {
         let result = part_two(&read_input(YEAR, DAY));
-        assert_eq!(result, None);
+        assert_eq!(result, read_answer(YEAR, DAY, 2));
     }
 }
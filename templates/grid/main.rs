@@ -0,0 +1,51 @@
+// Day %DAY%: %TITLE% (input: %INPUT_SHAPE%)
+
+use std::collections::HashMap;
+
+use advent_of_code::{grid::Coordinate, parsing::parse_grid};
+
+advent_of_code::solution!(%YEAR%, %DAY%);
+
+type Grid = HashMap<Coordinate<i32>, char>;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let _grid: Grid = parse_grid(input);
+    // TODO: solve the part
+    None
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let _grid: Grid = parse_grid(input);
+    // TODO: solve the part
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::commands::{read_answer, read_example, read_input};
+
+    #[test]
+    fn example_part_one() {
+        let result = part_one(&read_example(YEAR, DAY, %EXAMPLE_1%));
+        assert_eq!(result, %ANSWER_1%);
+    }
+
+    #[test]
+    fn input_part_one() {
+        let result = part_one(&read_input(YEAR, DAY));
+        assert_eq!(result, read_answer(YEAR, DAY, 1));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let result = part_two(&read_example(YEAR, DAY, %EXAMPLE_2%));
+        assert_eq!(result, %ANSWER_2%);
+    }
+
+    #[test]
+    fn input_part_two() {
+        let result = part_two(&read_input(YEAR, DAY));
+        assert_eq!(result, read_answer(YEAR, DAY, 2));
+    }
+}
@@ -0,0 +1,5 @@
+use super::parse::Item;
+
+pub(super) fn solve(items: &[Item]) -> usize {
+    items.len()
+}
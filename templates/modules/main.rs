@@ -0,0 +1,50 @@
+// Day %DAY%: %TITLE% (input: %INPUT_SHAPE%)
+
+mod logic;
+mod parse;
+
+use parse::{parse, Item};
+
+advent_of_code::solution!(%YEAR%, %DAY%, parse = parse);
+
+pub fn part_one(items: &[Item]) -> Option<u32> {
+    let _ = logic::solve(items);
+    // TODO: solve the part
+    None
+}
+
+pub fn part_two(items: &[Item]) -> Option<u32> {
+    let _ = logic::solve(items);
+    // TODO: solve the part
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::commands::{read_answer, read_example, read_input};
+
+    #[test]
+    fn example_part_one() {
+        let result = part_one(&parse(&read_example(YEAR, DAY, %EXAMPLE_1%)));
+        assert_eq!(result, %ANSWER_1%);
+    }
+
+    #[test]
+    fn input_part_one() {
+        let result = part_one(&parse(&read_input(YEAR, DAY)));
+        assert_eq!(result, read_answer(YEAR, DAY, 1));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let result = part_two(&parse(&read_example(YEAR, DAY, %EXAMPLE_2%)));
+        assert_eq!(result, %ANSWER_2%);
+    }
+
+    #[test]
+    fn input_part_two() {
+        let result = part_two(&parse(&read_input(YEAR, DAY)));
+        assert_eq!(result, read_answer(YEAR, DAY, 2));
+    }
+}
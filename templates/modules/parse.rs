@@ -0,0 +1,13 @@
+#[derive(Clone)]
+pub struct Item {
+    pub line: String,
+}
+
+pub(super) fn parse(input: &str) -> Vec<Item> {
+    input
+        .lines()
+        .map(|line| Item {
+            line: line.to_string(),
+        })
+        .collect()
+}
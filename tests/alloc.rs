@@ -0,0 +1,51 @@
+//! The allocator counters are global, so they are tested in their own binary, with a single test
+//! for no other test to allocate at the same time.
+#![cfg(feature = "count-alloc")]
+
+use std::hint::black_box;
+
+use advent_of_code::commands::alloc::{Allocations, Counter};
+
+#[test]
+fn test_counting_allocator() {
+    let counter = Counter::start().unwrap();
+    let buffer: Vec<u8> = black_box(Vec::with_capacity(1024));
+    drop(buffer);
+    assert_eq!(
+        counter.stop(),
+        Allocations {
+            count: 1,
+            bytes: 1024,
+            peak: 1024,
+        }
+    );
+
+    // A reallocation counts as a new block, live along with the old one.
+    let counter = Counter::start().unwrap();
+    let mut buffer: Vec<u8> = black_box(Vec::with_capacity(1024));
+    buffer.reserve_exact(4096);
+    drop(black_box(buffer));
+    assert_eq!(
+        counter.stop(),
+        Allocations {
+            count: 2,
+            bytes: 1024 + 4096,
+            peak: 1024 + 4096,
+        }
+    );
+
+    // The peak is relative to the bytes live when the counter is started.
+    let kept: Vec<u8> = black_box(Vec::with_capacity(2048));
+    let counter = Counter::start().unwrap();
+    let buffer: Vec<u8> = black_box(Vec::with_capacity(512));
+    drop(buffer);
+    drop(kept);
+    assert_eq!(
+        counter.stop(),
+        Allocations {
+            count: 1,
+            bytes: 512,
+            peak: 512,
+        }
+    );
+}
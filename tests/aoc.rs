@@ -0,0 +1,131 @@
+mod common;
+
+use std::fs::{read_to_string, write};
+
+use common::{FakeAoc, ANSWER, DAY, LEADERBOARD, SESSION, YEAR};
+
+fn stdout(output: &std::process::Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn test_download() {
+    let server = FakeAoc::start();
+    let dir = FakeAoc::workdir("download");
+    let input = dir.join(format!("data/inputs/{}/{:02}.txt", YEAR, DAY));
+
+    let output = server.run(&dir, &["download", &DAY.to_string()]);
+    assert!(output.status.success());
+    assert_eq!(
+        read_to_string(&input).unwrap(),
+        "red 42\nblue 38\nred 42\ngreen 40\nblue 38\n"
+    );
+    assert!(
+        read_to_string(dir.join(format!("data/inputs/{}/SHA256SUMS", YEAR)))
+            .unwrap()
+            .ends_with("  03.txt\n")
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, format!("/{}/day/{}/input", YEAR, DAY));
+    assert_eq!(
+        requests[0].header("cookie"),
+        Some(&*format!("session={}", SESSION))
+    );
+    assert!(requests[0]
+        .header("user-agent")
+        .is_some_and(|agent| agent.starts_with("advent_of_code/")));
+
+    // The input is kept, unless downloading it again is forced.
+    let output = server.run(&dir, &["download", &DAY.to_string()]);
+    assert!(stdout(&output).contains("Input already downloaded"));
+    write(&input, "red 42\n").unwrap();
+    let output = server.run(&dir, &["download", &DAY.to_string(), "--force"]);
+    assert!(output.status.success());
+    assert_eq!(read_to_string(&input).unwrap().lines().count(), 5);
+    assert_eq!(server.requests().len(), 2);
+
+    // An invalid throttle is reported rather than guessed.
+    let output = server
+        .command(&dir, &["download", &DAY.to_string(), "--force"])
+        .env("AOC_THROTTLE", "-1")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid AOC_THROTTLE `-1`"));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn test_read() {
+    let server = FakeAoc::start();
+    let dir = FakeAoc::workdir("read");
+
+    let output = server.run(&dir, &["read", &DAY.to_string()]);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("--- Day 3: Sock Drawer ---"));
+    assert!(dir
+        .join(format!("data/puzzles/{}/{:02}.html", YEAR, DAY))
+        .exists());
+
+    // Reading again is served from the cache, refreshing downloads the page again.
+    let output = server.run(&dir, &["read", &DAY.to_string()]);
+    assert!(output.status.success());
+    assert_eq!(server.requests().len(), 1);
+    let output = server.run(&dir, &["read", &DAY.to_string(), "--refresh"]);
+    assert!(output.status.success());
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn test_submit() {
+    let server = FakeAoc::start();
+    let dir = FakeAoc::workdir("submit");
+    let submit = |answer: u32| {
+        server.run(
+            &dir,
+            &["submit", &DAY.to_string(), "1", &answer.to_string()],
+        )
+    };
+
+    let output = submit(ANSWER - 1);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("too low"));
+
+    // Answers below a known too low answer are not submitted.
+    let output = submit(ANSWER - 2);
+    assert!(!output.status.success());
+    assert_eq!(server.requests().len(), 1);
+
+    let output = submit(ANSWER);
+    assert!(stdout(&output).contains("That's the right answer"));
+    assert_eq!(
+        read_to_string(dir.join(format!("data/answers/{}.txt", YEAR))).unwrap(),
+        format!("{:02}\t1\t{}\n", DAY, ANSWER)
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].method, "POST");
+    assert_eq!(requests[1].body, format!("level=1&answer={}", ANSWER));
+}
+
+#[test]
+fn test_leaderboard() {
+    let server = FakeAoc::start();
+    let dir = FakeAoc::workdir("leaderboard");
+
+    let output = server.run(&dir, &["leaderboard", &LEADERBOARD.to_string()]);
+    assert!(output.status.success());
+    let out = stdout(&output);
+    assert!(out.contains("Private leaderboard of Alice for 2015"));
+    assert!(out.contains("   1 | Alice "));
+
+    // The leaderboard is cached, and others are not available.
+    server.run(&dir, &["leaderboard", &LEADERBOARD.to_string()]);
+    assert_eq!(server.requests().len(), 1);
+    let output = server.run(&dir, &["leaderboard", "1"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not a member"));
+}
@@ -0,0 +1,193 @@
+//! A local stand-in for adventofcode.com, serving the fixtures of `tests/fixtures/`.
+
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, remove_dir_all},
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// The only puzzle served, the one of the fixtures.
+pub const YEAR: u16 = 2015;
+pub const DAY: u8 = 3;
+/// The answer accepted for the first part, any lower answer is too low.
+pub const ANSWER: u32 = 2;
+
+/// The only private leaderboard served, others redirect like for non-members.
+pub const LEADERBOARD: u64 = 101;
+
+pub const SESSION: &str = "fake-session";
+
+/// A request received by the server.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct FakeAoc {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+fn fixture(name: &str) -> String {
+    read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name),
+    )
+    .unwrap()
+}
+
+fn read_request(stream: &mut TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let (method, path) = (parts.next()?.to_string(), parts.next()?.to_string());
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        reader.read_line(&mut line).ok()?;
+        match line.trim_end().split_once(": ") {
+            Some((key, value)) => headers.push((key.to_string(), value.to_string())),
+            None => break,
+        }
+    }
+
+    let mut request = Request {
+        method,
+        path,
+        headers,
+        body: String::new(),
+    };
+    let length = request
+        .header("content-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    request.body = String::from_utf8(body).ok()?;
+    Some(request)
+}
+
+/// Answers a request like adventofcode.com would.
+fn respond(request: &Request) -> (u16, String) {
+    let day = format!("/{}/day/{}", YEAR, DAY);
+    if request.header("cookie") != Some(&format!("session={}", SESSION)) {
+        return (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string(),
+        );
+    }
+
+    if request
+        .path
+        .starts_with(&format!("/{}/leaderboard/private/view/", YEAR))
+    {
+        return if request.path.ends_with(&format!("/{}.json", LEADERBOARD)) {
+            (200, fixture("leaderboard.json"))
+        } else {
+            (302, String::new())
+        };
+    }
+
+    match (request.method.as_str(), request.path.strip_prefix(&day)) {
+        ("GET", Some("/input")) => (200, fixture("input.txt")),
+        ("GET", Some("")) => (
+            200,
+            format!(
+                "<!DOCTYPE html>\n<html lang=\"en-us\">\n<body>\n<main>\n{}</main>\n</body>\n</html>\n",
+                fixture("puzzle-part-one.html")
+            ),
+        ),
+        ("POST", Some("/answer")) => {
+            let answer = request
+                .body
+                .split('&')
+                .find_map(|field| field.strip_prefix("answer="))
+                .and_then(|answer| answer.parse::<u32>().ok());
+            match answer {
+                Some(ANSWER) => (200, fixture("answer-correct.html")),
+                _ => (200, fixture("answer-too-low.html")),
+            }
+        }
+        _ => (404, "404 Not Found".to_string()),
+    }
+}
+
+impl FakeAoc {
+    /// Starts the server on a free local port.
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let Some(request) = read_request(&mut stream) else {
+                    continue;
+                };
+                let (status, body) = respond(&request);
+                received.lock().unwrap().push(request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// Returns the requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Returns an empty working directory for a test, under the temporary directory.
+    pub fn workdir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = remove_dir_all(&dir);
+        create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Runs a command of the CLI in a working directory, against the server.
+    pub fn run(&self, dir: &Path, args: &[&str]) -> Output {
+        self.command(dir, args).output().unwrap()
+    }
+
+    /// Command running the binary against the server, to tweak before running it.
+    pub fn command(&self, dir: &Path, args: &[&str]) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_advent_of_code"));
+        command
+            .current_dir(dir)
+            .arg(YEAR.to_string())
+            .args(args)
+            .env("AOC_BASE_URL", &self.url)
+            .env("AOC_THROTTLE", "0")
+            .env("ADVENT_OF_CODE_SESSION", SESSION);
+        command
+    }
+}